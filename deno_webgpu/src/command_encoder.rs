@@ -150,6 +150,7 @@ pub fn op_webgpu_command_encoder_begin_render_pass(
         label: label.map(Cow::from),
         color_attachments: Cow::from(color_attachments),
         depth_stencil_attachment: processed_depth_stencil_attachment.as_ref(),
+        occlusion_query_set: None,
     };
 
     let render_pass = wgpu_core::command::RenderPass::new(command_encoder_resource.0, &descriptor);
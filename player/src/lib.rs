@@ -125,12 +125,14 @@ impl GlobalPlay for wgc::hub::Global<IdentityPassThroughFactory> {
                     base,
                     target_colors,
                     target_depth_stencil,
+                    occlusion_query_set,
                 } => {
                     self.command_encoder_run_render_pass_impl::<A>(
                         encoder,
                         base.as_ref(),
                         &target_colors,
                         target_depth_stencil.as_ref(),
+                        occlusion_query_set,
                     )
                     .unwrap();
                 }
@@ -413,6 +413,15 @@ impl RenderBundleEncoder {
                     size,
                 } => {
                     let scope = PassErrorScope::SetVertexBuffer(buffer_id);
+                    let max_vertex_buffers = device.limits.max_vertex_buffers;
+                    if slot >= max_vertex_buffers {
+                        return Err(RenderCommandError::VertexBufferIndexOutOfRange {
+                            index: slot,
+                            max: max_vertex_buffers,
+                        })
+                        .map_pass_err(scope);
+                    }
+
                     let buffer: &resource::Buffer<A> = state
                         .trackers
                         .buffers
@@ -559,6 +568,11 @@ impl RenderBundleEncoder {
                     check_buffer_usage(buffer.usage, wgt::BufferUsages::INDIRECT)
                         .map_pass_err(scope)?;
 
+                    if offset % 4 != 0 {
+                        return Err(RenderCommandError::UnalignedIndirectBufferOffset(offset))
+                            .map_pass_err(scope);
+                    }
+
                     buffer_memory_init_actions.extend(buffer.initialization_status.create_action(
                         buffer_id,
                         offset..(offset + mem::size_of::<wgt::DrawIndirectArgs>() as u64),
@@ -597,9 +611,14 @@ impl RenderBundleEncoder {
                     check_buffer_usage(buffer.usage, wgt::BufferUsages::INDIRECT)
                         .map_pass_err(scope)?;
 
+                    if offset % 4 != 0 {
+                        return Err(RenderCommandError::UnalignedIndirectBufferOffset(offset))
+                            .map_pass_err(scope);
+                    }
+
                     buffer_memory_init_actions.extend(buffer.initialization_status.create_action(
                         buffer_id,
-                        offset..(offset + mem::size_of::<wgt::DrawIndirectArgs>() as u64),
+                        offset..(offset + mem::size_of::<wgt::DrawIndexedIndirectArgs>() as u64),
                         MemoryInitKind::NeedsInitializedMemory,
                     ));
 
@@ -620,7 +639,9 @@ impl RenderBundleEncoder {
                 RenderCommand::PopDebugGroup => unimplemented!(),
                 RenderCommand::WriteTimestamp { .. } // Must check the WRITE_TIMESTAMP_INSIDE_PASSES feature
                 | RenderCommand::BeginPipelineStatisticsQuery { .. }
-                | RenderCommand::EndPipelineStatisticsQuery => unimplemented!(),
+                | RenderCommand::EndPipelineStatisticsQuery
+                | RenderCommand::BeginOcclusionQuery { .. }
+                | RenderCommand::EndOcclusionQuery => unimplemented!(),
                 RenderCommand::ExecuteBundle(_)
                 | RenderCommand::SetBlendConstant(_)
                 | RenderCommand::SetStencilReference(_)
@@ -909,7 +930,9 @@ impl<A: HalApi> RenderBundle<A> {
                 }
                 RenderCommand::WriteTimestamp { .. }
                 | RenderCommand::BeginPipelineStatisticsQuery { .. }
-                | RenderCommand::EndPipelineStatisticsQuery => {
+                | RenderCommand::EndPipelineStatisticsQuery
+                | RenderCommand::BeginOcclusionQuery { .. }
+                | RenderCommand::EndOcclusionQuery => {
                     return Err(ExecutionError::Unimplemented("queries"))
                 }
                 RenderCommand::ExecuteBundle(_)
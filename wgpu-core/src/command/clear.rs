@@ -440,6 +440,7 @@ fn clear_texture_via_render_passes<A: hal::Api>(
                     color_attachments,
                     depth_stencil_attachment,
                     multiview: None,
+                    occlusion_query_set: None,
                 });
                 encoder.end_render_pass();
             }
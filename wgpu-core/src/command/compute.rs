@@ -4,10 +4,11 @@ use crate::{
     },
     command::{
         bind::Binder,
-        end_pipeline_statistics_query,
+        end_query,
         memory_init::{fixup_discarded_surfaces, SurfacesInDiscardState},
         BasePass, BasePassRef, BindGroupStateChange, CommandBuffer, CommandEncoderError,
-        CommandEncoderStatus, MapPassErr, PassErrorScope, QueryUseError, StateChange,
+        CommandEncoderStatus, MapPassErr, PassErrorScope, QueryUseError, SimplifiedQueryType,
+        StateChange,
     },
     device::{MissingDownlevelFlags, MissingFeatures},
     error::{ErrorFormatter, PrettyError},
@@ -169,6 +170,8 @@ pub enum ComputePassErrorInner {
     InvalidQuerySet(id::QuerySetId),
     #[error("indirect buffer {0:?} is invalid or destroyed")]
     InvalidIndirectBuffer(id::BufferId),
+    #[error("indirect buffer offset {0:?} is not a multiple of 4")]
+    UnalignedIndirectBufferOffset(wgt::BufferAddress),
     #[error("indirect buffer uses bytes {offset}..{end_offset} which overruns indirect buffer of size {buffer_size}")]
     IndirectBufferOverrun {
         offset: u64,
@@ -183,6 +186,8 @@ pub enum ComputePassErrorInner {
     MissingBufferUsage(#[from] MissingBufferUsageError),
     #[error("cannot pop debug group, because number of pushed debug groups is zero")]
     InvalidPopDebugGroup,
+    #[error("{count} pushed debug groups were never popped")]
+    UnbalancedDebugGroups { count: u32 },
     #[error(transparent)]
     Dispatch(#[from] DispatchError),
     #[error(transparent)]
@@ -636,6 +641,11 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                     check_buffer_usage(indirect_buffer.usage, wgt::BufferUsages::INDIRECT)
                         .map_pass_err(scope)?;
 
+                    if offset % 4 != 0 {
+                        return Err(ComputePassErrorInner::UnalignedIndirectBufferOffset(offset))
+                            .map_pass_err(scope);
+                    }
+
                     let end_offset = offset + mem::size_of::<wgt::DispatchIndirectArgs>() as u64;
                     if end_offset > indirect_buffer.size {
                         return Err(ComputePassErrorInner::IndirectBufferOverrun {
@@ -740,9 +750,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         .map_pass_err(scope)?;
 
                     query_set
-                        .validate_and_begin_pipeline_statistics_query(
+                        .validate_and_begin_query(
                             raw,
                             query_set_id,
+                            SimplifiedQueryType::PipelineStatistics,
                             query_index,
                             None,
                             &mut active_query,
@@ -752,12 +763,18 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                 ComputeCommand::EndPipelineStatisticsQuery => {
                     let scope = PassErrorScope::EndPipelineStatisticsQuery;
 
-                    end_pipeline_statistics_query(raw, &*query_set_guard, &mut active_query)
-                        .map_pass_err(scope)?;
+                    end_query(raw, &*query_set_guard, &mut active_query).map_pass_err(scope)?;
                 }
             }
         }
 
+        if state.debug_scope_depth != 0 {
+            return Err(ComputePassErrorInner::UnbalancedDebugGroups {
+                count: state.debug_scope_depth,
+            })
+            .map_pass_err(init_scope);
+        }
+
         unsafe {
             raw.end_compute_pass();
         }
@@ -65,6 +65,8 @@ pub enum RenderCommandError {
     InvalidRenderBundle(id::RenderBundleId),
     #[error("bind group index {index} is greater than the device's requested `max_bind_group` limit {max}")]
     BindGroupIndexOutOfRange { index: u8, max: u32 },
+    #[error("vertex buffer index {index} is greater than the device's requested `max_vertex_buffers` limit {max}")]
+    VertexBufferIndexOutOfRange { index: u32, max: u32 },
     #[error("dynamic buffer offset {0} does not respect device's requested `{1}` limit {2}")]
     UnalignedBufferOffset(u64, &'static str, u32),
     #[error("number of buffer offsets ({actual}) does not match the number of dynamic bindings ({expected})")]
@@ -81,6 +83,8 @@ pub enum RenderCommandError {
     UsageConflict(#[from] UsageConflict),
     #[error("buffer {0:?} is destroyed")]
     DestroyedBuffer(id::BufferId),
+    #[error("indirect buffer offset {0:?} is not a multiple of 4")]
+    UnalignedIndirectBufferOffset(BufferAddress),
     #[error(transparent)]
     MissingBufferUsage(#[from] MissingBufferUsageError),
     #[error(transparent)]
@@ -246,5 +250,9 @@ pub enum RenderCommand {
         query_index: u32,
     },
     EndPipelineStatisticsQuery,
+    BeginOcclusionQuery {
+        query_index: u32,
+    },
+    EndOcclusionQuery,
     ExecuteBundle(id::RenderBundleId),
 }
@@ -101,6 +101,7 @@ pub struct CommandBuffer<A: HalApi> {
     texture_memory_actions: CommandBufferTextureMemoryActions,
     limits: wgt::Limits,
     support_clear_texture: bool,
+    debug_scope_depth: u32,
     #[cfg(feature = "trace")]
     pub(crate) commands: Option<Vec<TraceCommand>>,
 }
@@ -129,6 +130,7 @@ impl<A: HalApi> CommandBuffer<A> {
             texture_memory_actions: Default::default(),
             limits,
             support_clear_texture: features.contains(wgt::Features::CLEAR_TEXTURE),
+            debug_scope_depth: 0,
             #[cfg(feature = "trace")]
             commands: if enable_tracing {
                 Some(Vec::new())
@@ -333,6 +335,10 @@ pub enum CommandEncoderError {
     Invalid,
     #[error("command encoder must be active")]
     NotRecording,
+    #[error("cannot pop debug group, because number of pushed debug groups is zero")]
+    InvalidPopDebugGroup,
+    #[error("{count} pushed debug groups were never popped")]
+    UnbalancedDebugGroups { count: u32 },
 }
 
 impl<G: GlobalIdentityHandlerFactory> Global<G> {
@@ -349,6 +355,13 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
 
         let error = match cmd_buf_guard.get_mut(encoder_id) {
             Ok(cmd_buf) => match cmd_buf.status {
+                CommandEncoderStatus::Recording if cmd_buf.debug_scope_depth != 0 => {
+                    cmd_buf.encoder.discard();
+                    cmd_buf.status = CommandEncoderStatus::Error;
+                    Some(CommandEncoderError::UnbalancedDebugGroups {
+                        count: cmd_buf.debug_scope_depth,
+                    })
+                }
                 CommandEncoderStatus::Recording => {
                     cmd_buf.encoder.close();
                     cmd_buf.status = CommandEncoderStatus::Finished;
@@ -387,6 +400,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             list.push(TraceCommand::PushDebugGroup(label.to_string()));
         }
 
+        cmd_buf.debug_scope_depth += 1;
         let cmd_buf_raw = cmd_buf.encoder.open();
         unsafe {
             cmd_buf_raw.begin_debug_marker(label);
@@ -436,6 +450,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             list.push(TraceCommand::PopDebugGroup);
         }
 
+        if cmd_buf.debug_scope_depth == 0 {
+            return Err(CommandEncoderError::InvalidPopDebugGroup);
+        }
+        cmd_buf.debug_scope_depth -= 1;
         let cmd_buf_raw = cmd_buf.encoder.open();
         unsafe {
             cmd_buf_raw.end_debug_marker();
@@ -577,6 +595,10 @@ pub enum PassErrorScope {
     BeginPipelineStatisticsQuery,
     #[error("In a end_pipeline_statistics_query command")]
     EndPipelineStatisticsQuery,
+    #[error("In a begin_occlusion_query command")]
+    BeginOcclusionQuery,
+    #[error("In a end_occlusion_query command")]
+    EndOcclusionQuery,
     #[error("In a execute_bundle command")]
     ExecuteBundle,
     #[error("In a dispatch command, indirect:{indirect}")]
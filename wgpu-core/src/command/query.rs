@@ -220,21 +220,17 @@ impl<A: HalApi> QuerySet<A> {
         Ok(())
     }
 
-    pub(super) fn validate_and_begin_pipeline_statistics_query(
+    pub(super) fn validate_and_begin_query(
         &self,
         raw_encoder: &mut A::CommandEncoder,
         query_set_id: id::QuerySetId,
+        query_type: SimplifiedQueryType,
         query_index: u32,
         reset_state: Option<&mut QueryResetMap<A>>,
         active_query: &mut Option<(id::QuerySetId, u32)>,
     ) -> Result<(), QueryUseError> {
         let needs_reset = reset_state.is_none();
-        let query_set = self.validate_query(
-            query_set_id,
-            SimplifiedQueryType::PipelineStatistics,
-            query_index,
-            reset_state,
-        )?;
+        let query_set = self.validate_query(query_set_id, query_type, query_index, reset_state)?;
 
         if let Some((_old_id, old_idx)) = active_query.replace((query_set_id, query_index)) {
             return Err(QueryUseError::AlreadyStarted {
@@ -255,7 +251,7 @@ impl<A: HalApi> QuerySet<A> {
     }
 }
 
-pub(super) fn end_pipeline_statistics_query<A: HalApi>(
+pub(super) fn end_query<A: HalApi>(
     raw_encoder: &mut A::CommandEncoder,
     storage: &Storage<QuerySet<A>, id::QuerySetId>,
     active_query: &mut Option<(id::QuerySetId, u32)>,
@@ -3,11 +3,11 @@ use crate::{
     command::{
         self,
         bind::Binder,
-        end_pipeline_statistics_query,
+        end_query,
         memory_init::{fixup_discarded_surfaces, SurfacesInDiscardState},
         BasePass, BasePassRef, BindGroupStateChange, CommandBuffer, CommandEncoderError,
         CommandEncoderStatus, DrawError, ExecutionError, MapPassErr, PassErrorScope, QueryResetMap,
-        QueryUseError, RenderCommand, RenderCommandError, StateChange,
+        QueryUseError, RenderCommand, RenderCommandError, SimplifiedQueryType, StateChange,
     },
     device::{
         AttachmentData, Device, MissingDownlevelFlags, MissingFeatures,
@@ -176,6 +176,8 @@ pub struct RenderPassDescriptor<'a> {
     pub color_attachments: Cow<'a, [Option<RenderPassColorAttachment>]>,
     /// The depth and stencil attachment of the render pass, if any.
     pub depth_stencil_attachment: Option<&'a RenderPassDepthStencilAttachment>,
+    /// The query set that occlusion queries in the render pass write to, if any.
+    pub occlusion_query_set: Option<id::QuerySetId>,
 }
 
 #[cfg_attr(feature = "serial-pass", derive(Deserialize, Serialize))]
@@ -184,6 +186,7 @@ pub struct RenderPass {
     parent_id: id::CommandEncoderId,
     color_targets: ArrayVec<Option<RenderPassColorAttachment>, { hal::MAX_COLOR_ATTACHMENTS }>,
     depth_stencil_target: Option<RenderPassDepthStencilAttachment>,
+    occlusion_query_set_id: Option<id::QuerySetId>,
 
     // Resource binding dedupe state.
     #[cfg_attr(feature = "serial-pass", serde(skip))]
@@ -199,6 +202,7 @@ impl RenderPass {
             parent_id,
             color_targets: desc.color_attachments.iter().cloned().collect(),
             depth_stencil_target: desc.depth_stencil_attachment.cloned(),
+            occlusion_query_set_id: desc.occlusion_query_set,
 
             current_bind_groups: BindGroupStateChange::new(),
             current_pipeline: StateChange::new(),
@@ -215,6 +219,7 @@ impl RenderPass {
             base: self.base,
             target_colors: self.color_targets.into_iter().collect(),
             target_depth_stencil: self.depth_stencil_target,
+            occlusion_query_set: self.occlusion_query_set_id,
         }
     }
 
@@ -399,6 +404,11 @@ impl State {
         }
 
         if indexed {
+            // We have to have a buffer bound, regardless of the pipeline
+            if self.index.bound_buffer_view.is_none() {
+                return Err(DrawError::MissingIndexBuffer);
+            }
+
             // Pipeline expects an index buffer
             if let Some(pipeline_index_format) = self.index.pipeline_format {
                 // We have a buffer bound
@@ -491,6 +501,8 @@ pub enum RenderPassErrorInner {
     },
     #[error("cannot pop debug group, because number of pushed debug groups is zero")]
     InvalidPopDebugGroup,
+    #[error("{count} pushed debug groups were never popped")]
+    UnbalancedDebugGroups { count: u32 },
     #[error(transparent)]
     ResourceUsageConflict(#[from] UsageConflict),
     #[error("render bundle has incompatible targets, {0}")]
@@ -515,6 +527,10 @@ pub enum RenderPassErrorInner {
     Bind(#[from] BindError),
     #[error(transparent)]
     QueryUse(#[from] QueryUseError),
+    #[error("render pass has no occlusion query set to begin an occlusion query with")]
+    MissingOcclusionQuerySet,
+    #[error("occlusion query {query_index} was begun but never ended")]
+    UnendedOcclusionQuery { query_index: u32 },
     #[error("multiview layer count must match")]
     MultiViewMismatch,
     #[error(
@@ -648,6 +664,7 @@ impl<'a, A: HalApi> RenderPassInfo<'a, A> {
         label: Option<&str>,
         color_attachments: &[Option<RenderPassColorAttachment>],
         depth_stencil_attachment: Option<&RenderPassDepthStencilAttachment>,
+        occlusion_query_set: Option<&A::QuerySet>,
         cmd_buf: &mut CommandBuffer<A>,
         view_guard: &'a Storage<TextureView<A>, id::TextureViewId>,
         buffer_guard: &'a Storage<Buffer<A>, id::BufferId>,
@@ -974,6 +991,7 @@ impl<'a, A: HalApi> RenderPassInfo<'a, A> {
             color_attachments: &colors,
             depth_stencil_attachment: depth_stencil,
             multiview,
+            occlusion_query_set,
         };
         unsafe {
             cmd_buf.encoder.raw.begin_render_pass(&hal_desc);
@@ -1056,6 +1074,7 @@ impl<'a, A: HalApi> RenderPassInfo<'a, A> {
                     clear_value: (0.0, 0),
                 }),
                 multiview: self.multiview,
+                occlusion_query_set: None,
             };
             unsafe {
                 raw.begin_render_pass(&desc);
@@ -1080,6 +1099,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             pass.base.as_ref(),
             &pass.color_targets,
             pass.depth_stencil_target.as_ref(),
+            pass.occlusion_query_set_id,
         )
     }
 
@@ -1090,6 +1110,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
         base: BasePassRef<RenderCommand>,
         color_attachments: &[Option<RenderPassColorAttachment>],
         depth_stencil_attachment: Option<&RenderPassDepthStencilAttachment>,
+        occlusion_query_set_id: Option<id::QuerySetId>,
     ) -> Result<(), RenderPassError> {
         profiling::scope!("CommandEncoder::run_render_pass");
         let init_scope = PassErrorScope::Pass(encoder_id);
@@ -1117,6 +1138,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                     base: BasePass::from_ref(base),
                     target_colors: color_attachments.to_vec(),
                     target_depth_stencil: depth_stencil_attachment.cloned(),
+                    occlusion_query_set: occlusion_query_set_id,
                 });
             }
 
@@ -1137,11 +1159,33 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                 encoder_id
             );
 
+            let occlusion_query_set = match occlusion_query_set_id {
+                Some(query_set_id) => {
+                    let query_set: &resource::QuerySet<A> = cmd_buf
+                        .trackers
+                        .query_sets
+                        .add_single(&*query_set_guard, query_set_id)
+                        .ok_or(RenderCommandError::InvalidQuerySet(query_set_id))
+                        .map_pass_err(init_scope)?;
+                    let set_type = command::SimplifiedQueryType::from(query_set.desc.ty);
+                    if set_type != command::SimplifiedQueryType::Occlusion {
+                        return Err(QueryUseError::IncompatibleType {
+                            set_type,
+                            query_type: command::SimplifiedQueryType::Occlusion,
+                        })
+                        .map_pass_err(init_scope);
+                    }
+                    Some(query_set)
+                }
+                None => None,
+            };
+
             let mut info = RenderPassInfo::start(
                 device,
                 base.label,
                 color_attachments,
                 depth_stencil_attachment,
+                occlusion_query_set.map(|query_set| &query_set.raw),
                 cmd_buf,
                 &*view_guard,
                 &*buffer_guard,
@@ -1177,6 +1221,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             let mut dynamic_offset_count = 0;
             let mut string_offset = 0;
             let mut active_query = None;
+            let mut active_occlusion_query = None;
             let mut query_reset_state = QueryResetMap::new();
 
             for command in base.commands {
@@ -1427,6 +1472,15 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                         size,
                     } => {
                         let scope = PassErrorScope::SetVertexBuffer(buffer_id);
+                        let max_vertex_buffers = device.limits.max_vertex_buffers;
+                        if slot >= max_vertex_buffers {
+                            return Err(RenderCommandError::VertexBufferIndexOutOfRange {
+                                index: slot,
+                                max: max_vertex_buffers,
+                            })
+                            .map_pass_err(scope);
+                        }
+
                         let buffer: &Buffer<A> = info
                             .usage_scope
                             .buffers
@@ -1701,6 +1755,11 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             .ok_or(RenderCommandError::DestroyedBuffer(buffer_id))
                             .map_pass_err(scope)?;
 
+                        if offset % 4 != 0 {
+                            return Err(RenderCommandError::UnalignedIndirectBufferOffset(offset))
+                                .map_pass_err(scope);
+                        }
+
                         let actual_count = count.map_or(1, |c| c.get());
 
                         let end_offset = offset + stride as u64 * actual_count as u64;
@@ -1788,6 +1847,17 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             .ok_or(RenderCommandError::DestroyedBuffer(count_buffer_id))
                             .map_pass_err(scope)?;
 
+                        if offset % 4 != 0 {
+                            return Err(RenderCommandError::UnalignedIndirectBufferOffset(offset))
+                                .map_pass_err(scope);
+                        }
+                        if count_buffer_offset % 4 != 0 {
+                            return Err(RenderCommandError::UnalignedIndirectBufferOffset(
+                                count_buffer_offset,
+                            ))
+                            .map_pass_err(scope);
+                        }
+
                         let end_offset = offset + stride * max_count as u64;
                         if end_offset > indirect_buffer.size {
                             return Err(RenderPassErrorInner::IndirectBufferOverrun {
@@ -1915,9 +1985,10 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                             .map_pass_err(scope)?;
 
                         query_set
-                            .validate_and_begin_pipeline_statistics_query(
+                            .validate_and_begin_query(
                                 raw,
                                 query_set_id,
+                                SimplifiedQueryType::PipelineStatistics,
                                 query_index,
                                 Some(&mut query_reset_state),
                                 &mut active_query,
@@ -1927,7 +1998,32 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                     RenderCommand::EndPipelineStatisticsQuery => {
                         let scope = PassErrorScope::EndPipelineStatisticsQuery;
 
-                        end_pipeline_statistics_query(raw, &*query_set_guard, &mut active_query)
+                        end_query(raw, &*query_set_guard, &mut active_query).map_pass_err(scope)?;
+                    }
+                    RenderCommand::BeginOcclusionQuery { query_index } => {
+                        let scope = PassErrorScope::BeginOcclusionQuery;
+
+                        let query_set_id = occlusion_query_set_id
+                            .ok_or(RenderPassErrorInner::MissingOcclusionQuerySet)
+                            .map_pass_err(scope)?;
+                        // The query set was validated and tracked when the pass began
+                        let query_set = occlusion_query_set.unwrap();
+
+                        query_set
+                            .validate_and_begin_query(
+                                raw,
+                                query_set_id,
+                                SimplifiedQueryType::Occlusion,
+                                query_index,
+                                Some(&mut query_reset_state),
+                                &mut active_occlusion_query,
+                            )
+                            .map_pass_err(scope)?;
+                    }
+                    RenderCommand::EndOcclusionQuery => {
+                        let scope = PassErrorScope::EndOcclusionQuery;
+
+                        end_query(raw, &*query_set_guard, &mut active_occlusion_query)
                             .map_pass_err(scope)?;
                     }
                     RenderCommand::ExecuteBundle(bundle_id) => {
@@ -2006,6 +2102,17 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                 }
             }
 
+            if state.debug_scope_depth != 0 {
+                return Err(RenderPassErrorInner::UnbalancedDebugGroups {
+                    count: state.debug_scope_depth,
+                })
+                .map_pass_err(init_scope);
+            }
+            if let Some((_, query_index)) = active_occlusion_query {
+                return Err(RenderPassErrorInner::UnendedOcclusionQuery { query_index })
+                    .map_pass_err(init_scope);
+            }
+
             log::trace!("Merging renderpass into cmd_buf {:?}", encoder_id);
             let (trackers, pending_discard_init_fixups) =
                 info.finish(raw, &*texture_guard).map_pass_err(init_scope)?;
@@ -2439,6 +2546,21 @@ pub mod render_ffi {
             .push(RenderCommand::EndPipelineStatisticsQuery);
     }
 
+    #[no_mangle]
+    pub extern "C" fn wgpu_render_pass_begin_occlusion_query(
+        pass: &mut RenderPass,
+        query_index: u32,
+    ) {
+        pass.base
+            .commands
+            .push(RenderCommand::BeginOcclusionQuery { query_index });
+    }
+
+    #[no_mangle]
+    pub extern "C" fn wgpu_render_pass_end_occlusion_query(pass: &mut RenderPass) {
+        pass.base.commands.push(RenderCommand::EndOcclusionQuery);
+    }
+
     /// # Safety
     ///
     /// This function is unsafe as there is no guarantee that the given pointer is
@@ -40,6 +40,8 @@ pub enum TransferError {
     InvalidTexture(TextureId),
     #[error("Source and destination cannot be the same buffer")]
     SameSourceDestinationBuffer,
+    #[error("Source and destination cannot be overlapping subresources of the same texture")]
+    OverlappingTextureSubresources,
     #[error("source buffer/texture is missing the `COPY_SRC` usage flag")]
     MissingCopySrcUsageFlag,
     #[error("destination buffer/texture is missing the `COPY_DST` usage flag")]
@@ -922,6 +924,15 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
         if src_tex_base.aspect != dst_tex_base.aspect {
             return Err(TransferError::MismatchedAspects.into());
         }
+        // A copy within the same texture would have to be in both `COPY_SRC` and
+        // `COPY_DST` states for the overlapping subresources at the same time.
+        if source.texture == destination.texture
+            && src_range.mips == dst_range.mips
+            && src_range.layers.start < dst_range.layers.end
+            && dst_range.layers.start < src_range.layers.end
+        {
+            return Err(TransferError::OverlappingTextureSubresources.into());
+        }
 
         // Handle texture init *before* dealing with barrier transitions so we have an easier time inserting "immediate-inits" that may be required by prior discards in rare cases.
         handle_src_texture_init(cmd_buf, device, source, copy_size, &texture_guard)?;
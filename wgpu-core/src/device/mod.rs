@@ -945,16 +945,20 @@ impl<A: HalApi> Device<A> {
 
         let required_level_count =
             desc.range.base_mip_level + desc.range.mip_level_count.map_or(1, |count| count.get());
-        let required_layer_count = match desc.range.array_layer_count {
-            Some(count) => desc.range.base_array_layer + count.get(),
-            None => match view_dim {
-                wgt::TextureViewDimension::D1
-                | wgt::TextureViewDimension::D2
-                | wgt::TextureViewDimension::D3 => 1,
-                wgt::TextureViewDimension::Cube => 6,
-                _ => texture.desc.array_layer_count(),
-            },
-        };
+        let required_layer_count = desc.range.base_array_layer
+            + match desc.range.array_layer_count {
+                Some(count) => count.get(),
+                None => match view_dim {
+                    wgt::TextureViewDimension::D1
+                    | wgt::TextureViewDimension::D2
+                    | wgt::TextureViewDimension::D3 => 1,
+                    wgt::TextureViewDimension::Cube => 6,
+                    _ => texture
+                        .desc
+                        .array_layer_count()
+                        .saturating_sub(desc.range.base_array_layer),
+                },
+            };
         let level_end = texture.full_range.mips.end;
         let layer_end = texture.full_range.layers.end;
         if required_level_count > level_end {
@@ -970,24 +974,6 @@ impl<A: HalApi> Device<A> {
             });
         };
 
-        match view_dim {
-            TextureViewDimension::Cube if required_layer_count != 6 => {
-                return Err(
-                    resource::CreateTextureViewError::InvalidCubemapTextureDepth {
-                        depth: required_layer_count,
-                    },
-                )
-            }
-            TextureViewDimension::CubeArray if required_layer_count % 6 != 0 => {
-                return Err(
-                    resource::CreateTextureViewError::InvalidCubemapArrayTextureDepth {
-                        depth: required_layer_count,
-                    },
-                )
-            }
-            _ => {}
-        }
-
         let full_aspect = hal::FormatAspects::from(texture.desc.format);
         let select_aspect = hal::FormatAspects::from(desc.range.aspect);
         if (full_aspect & select_aspect).is_empty() {
@@ -1001,23 +987,44 @@ impl<A: HalApi> Device<A> {
             .range
             .mip_level_count
             .map_or(level_end, |_| required_level_count);
-        let end_layer = desc
-            .range
-            .array_layer_count
-            .map_or(layer_end, |_| required_layer_count);
         let selector = TextureSelector {
             mips: desc.range.base_mip_level..end_level,
-            layers: desc.range.base_array_layer..end_layer,
+            layers: desc.range.base_array_layer..required_layer_count,
         };
 
         let view_layer_count = selector.layers.end - selector.layers.start;
+        match view_dim {
+            TextureViewDimension::Cube if view_layer_count != 6 => {
+                return Err(
+                    resource::CreateTextureViewError::InvalidCubemapTextureDepth {
+                        depth: view_layer_count,
+                    },
+                )
+            }
+            TextureViewDimension::CubeArray if view_layer_count % 6 != 0 => {
+                return Err(
+                    resource::CreateTextureViewError::InvalidCubemapArrayTextureDepth {
+                        depth: view_layer_count,
+                    },
+                )
+            }
+            TextureViewDimension::Cube | TextureViewDimension::CubeArray
+                if texture.desc.size.width != texture.desc.size.height =>
+            {
+                return Err(resource::CreateTextureViewError::InvalidCubeTextureViewSize);
+            }
+            _ => {}
+        }
+
         let layer_check_ok = match view_dim {
             wgt::TextureViewDimension::D1
             | wgt::TextureViewDimension::D2
             | wgt::TextureViewDimension::D3 => view_layer_count == 1,
-            wgt::TextureViewDimension::D2Array => true,
+            wgt::TextureViewDimension::D2Array => view_layer_count != 0,
             wgt::TextureViewDimension::Cube => view_layer_count == 6,
-            wgt::TextureViewDimension::CubeArray => view_layer_count % 6 == 0,
+            wgt::TextureViewDimension::CubeArray => {
+                view_layer_count != 0 && view_layer_count % 6 == 0
+            }
         };
         if !layer_check_ok {
             return Err(resource::CreateTextureViewError::InvalidArrayLayerCount {
@@ -1073,7 +1080,10 @@ impl<A: HalApi> Device<A> {
             format,
             dimension: view_dim,
             usage,
-            range: desc.range.clone(),
+            range: wgt::ImageSubresourceRange {
+                array_layer_count: NonZeroU32::new(view_layer_count),
+                ..desc.range.clone()
+            },
         };
 
         let raw = unsafe {
@@ -1139,6 +1149,13 @@ impl<A: HalApi> Device<A> {
             {
                 std::num::NonZeroU8::new(clamp)
             } else {
+                // A clamp of 1 means no anisotropy, so there is nothing to ignore.
+                if clamp > 1 {
+                    log::warn!(
+                        "Anisotropic clamp {} is ignored, because the adapter doesn't support anisotropic filtering",
+                        clamp
+                    );
+                }
                 None
             }
         } else {
@@ -1894,7 +1911,7 @@ impl<A: HalApi> Device<A> {
                         .views
                         .add_single(&*texture_view_guard, id)
                         .ok_or(Error::InvalidTextureView(id))?;
-                    let (pub_usage, internal_use) = Self::texture_use_parameters(
+                    let (pub_usage, internal_use) = self.texture_use_parameters(
                         binding,
                         decl,
                         view,
@@ -1926,7 +1943,7 @@ impl<A: HalApi> Device<A> {
                             .add_single(&*texture_view_guard, id)
                             .ok_or(Error::InvalidTextureView(id))?;
                         let (pub_usage, internal_use) =
-                            Self::texture_use_parameters(binding, decl, view,
+                            self.texture_use_parameters(binding, decl, view,
                                                          "SampledTextureArray, ReadonlyStorageTextureArray or WriteonlyStorageTextureArray")?;
                         Self::create_texture_binding(
                             view,
@@ -2034,6 +2051,7 @@ impl<A: HalApi> Device<A> {
     }
 
     fn texture_use_parameters(
+        &self,
         binding: u32,
         decl: &wgt::BindGroupLayoutEntry,
         view: &crate::resource::TextureView<A>,
@@ -2055,6 +2073,13 @@ impl<A: HalApi> Device<A> {
                 multisampled,
             } => {
                 use wgt::TextureSampleType as Tst;
+                // A stencil-only view is sampled as unsigned integers.
+                let view_sample_type = if view.desc.aspects() == hal::FormatAspects::STENCIL {
+                    self.require_downlevel_flags(wgt::DownlevelFlags::STENCIL_TEXTURE_VIEWS)?;
+                    Tst::Uint
+                } else {
+                    format_info.sample_type
+                };
                 if multisampled != (view.samples != 1) {
                     return Err(Error::InvalidTextureMultisample {
                         binding,
@@ -2062,7 +2087,7 @@ impl<A: HalApi> Device<A> {
                         view_samples: view.samples,
                     });
                 }
-                match (sample_type, format_info.sample_type) {
+                match (sample_type, view_sample_type) {
                     (Tst::Uint, Tst::Uint) |
                     (Tst::Sint, Tst::Sint) |
                     (Tst::Depth, Tst::Depth) |
@@ -2657,6 +2682,11 @@ impl<A: HalApi> Device<A> {
             if sc == 0 || sc > 32 || !conv::is_power_of_two_u32(sc) {
                 return Err(pipeline::CreateRenderPipelineError::InvalidSampleCount(sc));
             }
+            if desc.multisample.alpha_to_coverage_enabled && sc == 1 {
+                return Err(
+                    pipeline::CreateRenderPipelineError::AlphaToCoverageWithoutMultisampling,
+                );
+            }
             sc
         };
 
@@ -5089,7 +5119,9 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                 Ok(()) => (),
                 Err(error) => {
                     break match error {
-                        hal::SurfaceError::Outdated | hal::SurfaceError::Lost => E::InvalidSurface,
+                        hal::SurfaceError::Outdated
+                        | hal::SurfaceError::Lost
+                        | hal::SurfaceError::Timeout => E::InvalidSurface,
                         hal::SurfaceError::Device(error) => E::Device(error.into()),
                         hal::SurfaceError::Other(message) => {
                             log::error!("surface configuration failed: {}", message);
@@ -178,6 +178,7 @@ pub enum Command {
         base: crate::command::BasePass<crate::command::RenderCommand>,
         target_colors: Vec<Option<crate::command::RenderPassColorAttachment>>,
         target_depth_stencil: Option<crate::command::RenderPassDepthStencilAttachment>,
+        occlusion_query_set: Option<id::QuerySetId>,
     },
 }
 
@@ -134,7 +134,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                     FRAME_TIMEOUT_MS as u64,
                 )))
         } {
-            Ok(Some(ast)) => {
+            Ok(ast) => {
                 let clear_view_desc = hal::TextureViewDescriptor {
                     label: Some("(wgpu internal) clear surface texture view"),
                     format: config.format,
@@ -221,11 +221,11 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
                 };
                 (Some(id.0), status)
             }
-            Ok(None) => (None, Status::Timeout),
             Err(err) => (
                 None,
                 match err {
                     hal::SurfaceError::Lost => Status::Lost,
+                    hal::SurfaceError::Timeout => Status::Timeout,
                     hal::SurfaceError::Device(err) => {
                         return Err(DeviceError::from(err).into());
                     }
@@ -325,6 +325,7 @@ impl<G: GlobalIdentityHandlerFactory> Global<G> {
             Ok(()) => Ok(Status::Good),
             Err(err) => match err {
                 hal::SurfaceError::Lost => Ok(Status::Lost),
+                hal::SurfaceError::Timeout => Ok(Status::Timeout),
                 hal::SurfaceError::Device(err) => Err(SurfaceError::from(DeviceError::from(err))),
                 hal::SurfaceError::Outdated => Ok(Status::Outdated),
                 hal::SurfaceError::Other(msg) => {
@@ -0,0 +1,306 @@
+//! This example shows basic compute usage of wgpu-hal by zeroing
+//! a storage buffer with a single dispatch, and reading it back.
+//! It doesn't need a window or a surface.
+extern crate wgpu_hal as hal;
+
+use hal::{Adapter as _, CommandEncoder as _, Device as _, Instance as _, Queue as _};
+
+use std::{iter, mem, ptr};
+
+const ELEMENT_COUNT: usize = 256;
+const WORKGROUP_SIZE: u32 = 64;
+
+#[cfg(all(feature = "metal"))]
+type Api = hal::api::Metal;
+#[cfg(all(feature = "vulkan", not(feature = "metal")))]
+type Api = hal::api::Vulkan;
+#[cfg(all(feature = "gles", not(feature = "metal"), not(feature = "vulkan")))]
+type Api = hal::api::Gles;
+#[cfg(all(
+    feature = "dx12",
+    not(feature = "metal"),
+    not(feature = "vulkan"),
+    not(feature = "gles")
+))]
+type Api = hal::api::Dx12;
+#[cfg(not(any(
+    feature = "metal",
+    feature = "vulkan",
+    feature = "gles",
+    feature = "dx12"
+)))]
+type Api = hal::api::Empty;
+
+fn run<A: hal::Api>() -> Result<(), hal::InstanceError> {
+    let instance_desc = hal::InstanceDescriptor {
+        name: "compute example",
+        flags: if cfg!(debug_assertions) {
+            hal::InstanceFlags::all()
+        } else {
+            hal::InstanceFlags::empty()
+        },
+    };
+    let instance = unsafe { A::Instance::init(&instance_desc)? };
+
+    let (adapter, capabilities) = unsafe {
+        let mut adapters = instance.enumerate_adapters();
+        if adapters.is_empty() {
+            return Err(hal::InstanceError);
+        }
+        let exposed = adapters.swap_remove(0);
+        (exposed.adapter, exposed.capabilities)
+    };
+    if !capabilities
+        .downlevel
+        .flags
+        .contains(wgt::DownlevelFlags::COMPUTE_SHADERS)
+    {
+        log::error!("The adapter doesn't support DownlevelFlags::COMPUTE_SHADERS");
+        return Err(hal::InstanceError);
+    }
+    let hal::OpenDevice { device, mut queue } = unsafe {
+        adapter
+            .open(wgt::Features::empty(), &wgt::Limits::default())
+            .unwrap()
+    };
+
+    let naga_shader = {
+        let shader_file = std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("examples")
+            .join("halcompute")
+            .join("shader.wgsl");
+        let source = std::fs::read_to_string(shader_file).unwrap();
+        let module = naga::front::wgsl::Parser::new().parse(&source).unwrap();
+        let info = naga::valid::Validator::new(
+            naga::valid::ValidationFlags::all(),
+            naga::valid::Capabilities::empty(),
+        )
+        .validate(&module)
+        .unwrap();
+        hal::NagaShader { module, info }
+    };
+    let shader_desc = hal::ShaderModuleDescriptor {
+        label: None,
+        runtime_checks: false,
+    };
+    let shader = unsafe {
+        device
+            .create_shader_module(&shader_desc, hal::ShaderInput::Naga(naga_shader))
+            .unwrap()
+    };
+
+    let data_size = (ELEMENT_COUNT * mem::size_of::<u32>()) as wgt::BufferAddress;
+
+    let bgl_desc = hal::BindGroupLayoutDescriptor {
+        label: None,
+        flags: hal::BindGroupLayoutFlags::empty(),
+        entries: &[wgt::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgt::ShaderStages::COMPUTE,
+            ty: wgt::BindingType::Buffer {
+                ty: wgt::BufferBindingType::Storage { read_only: false },
+                has_dynamic_offset: false,
+                min_binding_size: wgt::BufferSize::new(data_size),
+            },
+            count: None,
+        }],
+    };
+    let bind_group_layout = unsafe { device.create_bind_group_layout(&bgl_desc).unwrap() };
+
+    let pipeline_layout_desc = hal::PipelineLayoutDescriptor {
+        label: None,
+        flags: hal::PipelineLayoutFlags::empty(),
+        bind_group_layouts: &[&bind_group_layout],
+        push_constant_ranges: &[],
+    };
+    let pipeline_layout = unsafe {
+        device
+            .create_pipeline_layout(&pipeline_layout_desc)
+            .unwrap()
+    };
+
+    let pipeline_desc = hal::ComputePipelineDescriptor {
+        label: Some("zero"),
+        layout: &pipeline_layout,
+        stage: hal::ProgrammableStage {
+            module: &shader,
+            entry_point: "cs_main",
+        },
+    };
+    let pipeline = unsafe { device.create_compute_pipeline(&pipeline_desc).unwrap() };
+
+    // Fill the storage buffer with garbage first, so that zeroing it is observable.
+    let staging_buffer_desc = hal::BufferDescriptor {
+        label: Some("stage"),
+        size: data_size,
+        usage: hal::BufferUses::MAP_WRITE | hal::BufferUses::COPY_SRC,
+        memory_flags: hal::MemoryFlags::TRANSIENT | hal::MemoryFlags::PREFER_COHERENT,
+    };
+    let staging_buffer = unsafe {
+        let buffer = device.create_buffer(&staging_buffer_desc).unwrap();
+        let mapping = device.map_buffer(&buffer, 0..data_size).unwrap();
+        ptr::write_bytes(mapping.ptr.as_ptr(), 0xFF, data_size as usize);
+        if !mapping.is_coherent {
+            device.flush_mapped_ranges(&buffer, iter::once(0..data_size));
+        }
+        device.unmap_buffer(&buffer).unwrap();
+        buffer
+    };
+
+    let storage_buffer_desc = hal::BufferDescriptor {
+        label: Some("storage"),
+        size: data_size,
+        usage: hal::BufferUses::STORAGE_READ_WRITE
+            | hal::BufferUses::COPY_DST
+            | hal::BufferUses::COPY_SRC,
+        memory_flags: hal::MemoryFlags::empty(),
+    };
+    let storage_buffer = unsafe { device.create_buffer(&storage_buffer_desc).unwrap() };
+
+    let readback_buffer_desc = hal::BufferDescriptor {
+        label: Some("readback"),
+        size: data_size,
+        usage: hal::BufferUses::MAP_READ | hal::BufferUses::COPY_DST,
+        memory_flags: hal::MemoryFlags::empty(),
+    };
+    let readback_buffer = unsafe { device.create_buffer(&readback_buffer_desc).unwrap() };
+
+    let bind_group = {
+        let buffer_binding = hal::BufferBinding {
+            buffer: &storage_buffer,
+            offset: 0,
+            size: None,
+        };
+        let bind_group_desc = hal::BindGroupDescriptor {
+            label: Some("storage"),
+            layout: &bind_group_layout,
+            buffers: &[buffer_binding],
+            samplers: &[],
+            textures: &[],
+            entries: &[hal::BindGroupEntry {
+                binding: 0,
+                resource_index: 0,
+                count: 1,
+            }],
+        };
+        unsafe { device.create_bind_group(&bind_group_desc).unwrap() }
+    };
+
+    let cmd_encoder_desc = hal::CommandEncoderDescriptor {
+        label: None,
+        queue: &queue,
+    };
+    let mut cmd_encoder = unsafe { device.create_command_encoder(&cmd_encoder_desc).unwrap() };
+    let region = hal::BufferCopy {
+        src_offset: 0,
+        dst_offset: 0,
+        size: wgt::BufferSize::new(data_size).unwrap(),
+    };
+    unsafe {
+        cmd_encoder.begin_encoding(Some("compute")).unwrap();
+        cmd_encoder.transition_buffers(
+            [
+                hal::BufferBarrier {
+                    buffer: &staging_buffer,
+                    usage: hal::BufferUses::MAP_WRITE..hal::BufferUses::COPY_SRC,
+                },
+                hal::BufferBarrier {
+                    buffer: &storage_buffer,
+                    usage: hal::BufferUses::empty()..hal::BufferUses::COPY_DST,
+                },
+            ]
+            .into_iter(),
+        );
+        cmd_encoder.copy_buffer_to_buffer(&staging_buffer, &storage_buffer, iter::once(region));
+        cmd_encoder.transition_buffers(iter::once(hal::BufferBarrier {
+            buffer: &storage_buffer,
+            usage: hal::BufferUses::COPY_DST..hal::BufferUses::STORAGE_READ_WRITE,
+        }));
+
+        cmd_encoder.begin_compute_pass(&hal::ComputePassDescriptor {
+            label: Some("zero"),
+        });
+        cmd_encoder.set_compute_pipeline(&pipeline);
+        cmd_encoder.set_bind_group(&pipeline_layout, 0, &bind_group, &[]);
+        cmd_encoder.dispatch([
+            (ELEMENT_COUNT as u32 + WORKGROUP_SIZE - 1) / WORKGROUP_SIZE,
+            1,
+            1,
+        ]);
+        cmd_encoder.end_compute_pass();
+
+        cmd_encoder.transition_buffers(
+            [
+                hal::BufferBarrier {
+                    buffer: &storage_buffer,
+                    usage: hal::BufferUses::STORAGE_READ_WRITE..hal::BufferUses::COPY_SRC,
+                },
+                hal::BufferBarrier {
+                    buffer: &readback_buffer,
+                    usage: hal::BufferUses::empty()..hal::BufferUses::COPY_DST,
+                },
+            ]
+            .into_iter(),
+        );
+        cmd_encoder.copy_buffer_to_buffer(&storage_buffer, &readback_buffer, iter::once(region));
+        cmd_encoder.transition_buffers(iter::once(hal::BufferBarrier {
+            buffer: &readback_buffer,
+            usage: hal::BufferUses::COPY_DST..hal::BufferUses::MAP_READ,
+        }));
+    }
+
+    let fence_value = 1;
+    let mut fence = unsafe { device.create_fence().unwrap() };
+    unsafe {
+        let cmd_buf = cmd_encoder.end_encoding().unwrap();
+        queue
+            .submit(&[&cmd_buf], Some((&mut fence, fence_value)))
+            .unwrap();
+        device.wait(&fence, fence_value, !0).unwrap();
+        cmd_encoder.reset_all(iter::once(cmd_buf));
+    }
+
+    let result = unsafe {
+        let mapping = device.map_buffer(&readback_buffer, 0..data_size).unwrap();
+        if !mapping.is_coherent {
+            device.invalidate_mapped_ranges(&readback_buffer, iter::once(0..data_size));
+        }
+        let mut result = vec![0u32; ELEMENT_COUNT];
+        ptr::copy_nonoverlapping(
+            mapping.ptr.as_ptr(),
+            result.as_mut_ptr() as *mut u8,
+            data_size as usize,
+        );
+        device.unmap_buffer(&readback_buffer).unwrap();
+        result
+    };
+
+    unsafe {
+        device.destroy_command_encoder(cmd_encoder);
+        device.destroy_fence(fence);
+        device.destroy_bind_group(bind_group);
+        device.destroy_buffer(readback_buffer);
+        device.destroy_buffer(storage_buffer);
+        device.destroy_buffer(staging_buffer);
+        device.destroy_compute_pipeline(pipeline);
+        device.destroy_shader_module(shader);
+        device.destroy_pipeline_layout(pipeline_layout);
+        device.destroy_bind_group_layout(bind_group_layout);
+        device.exit(queue);
+        drop(adapter);
+    }
+
+    let non_zero = result.iter().filter(|&&value| value != 0).count();
+    assert_eq!(
+        non_zero, 0,
+        "{} elements out of {} are not zeroed",
+        non_zero, ELEMENT_COUNT
+    );
+    println!("All {} elements are zeroed", ELEMENT_COUNT);
+    Ok(())
+}
+
+fn main() {
+    env_logger::init();
+    run::<Api>().expect("Selected backend is not supported");
+}
@@ -0,0 +1,16 @@
+struct Data {
+    values: array<u32>,
+};
+
+@group(0)
+@binding(0)
+var<storage, read_write> data: Data;
+
+@compute
+@workgroup_size(64)
+fn cs_main(@builtin(global_invocation_id) global_id: vec3<u32>) {
+    // The last workgroup can reach past the end of the buffer.
+    if (global_id.x < arrayLength(&data.values)) {
+        data.values[global_id.x] = 0u;
+    }
+}
@@ -23,7 +23,23 @@ struct Globals {
     pad: [f32; 2],
 }
 
-#[repr(C, align(256))]
+impl Globals {
+    fn new(width: u32, height: u32) -> Self {
+        Globals {
+            // cgmath::ortho() projection
+            mvp: [
+                [2.0 / width as f32, 0.0, 0.0, 0.0],
+                [0.0, 2.0 / height as f32, 0.0, 0.0],
+                [0.0, 0.0, 1.0, 0.0],
+                [-1.0, -1.0, 0.0, 1.0],
+            ],
+            size: [BUNNY_SIZE; 2],
+            pad: [0.0; 2],
+        }
+    }
+}
+
+#[repr(C)]
 #[derive(Clone, Copy)]
 struct Locals {
     position: [f32; 2],
@@ -57,7 +73,9 @@ struct Example<A: hal::Api> {
     instance: A::Instance,
     adapter: A::Adapter,
     surface: A::Surface,
-    surface_format: wgt::TextureFormat,
+    surface_config: hal::SurfaceConfiguration,
+    present_modes: Vec<wgt::PresentMode>,
+    surface_extents: std::ops::RangeInclusive<wgt::Extent3d>,
     device: A::Device,
     queue: A::Queue,
     global_group: A::BindGroup,
@@ -69,7 +87,6 @@ struct Example<A: hal::Api> {
     pipeline: A::RenderPipeline,
     bunnies: Vec<Locals>,
     local_buffer: A::Buffer,
-    local_alignment: u32,
     global_buffer: A::Buffer,
     sampler: A::Sampler,
     texture: A::Texture,
@@ -99,8 +116,18 @@ impl<A: hal::Api> Example<A> {
                 return Err(hal::InstanceError);
             }
             let exposed = adapters.swap_remove(0);
+            log::info!("Adapter: {:#?}", exposed.info);
             (exposed.adapter, exposed.capabilities)
         };
+        // Bunnies are read from a storage buffer in the vertex shader.
+        if !capabilities
+            .downlevel
+            .flags
+            .contains(wgt::DownlevelFlags::VERTEX_STORAGE)
+        {
+            log::error!("The adapter doesn't support DownlevelFlags::VERTEX_STORAGE");
+            return Err(hal::InstanceError);
+        }
         let surface_caps =
             unsafe { adapter.surface_capabilities(&surface) }.ok_or(hal::InstanceError)?;
         log::info!("Surface caps: {:#?}", surface_caps);
@@ -198,8 +225,8 @@ impl<A: hal::Api> Example<A> {
                 binding: 0,
                 visibility: wgt::ShaderStages::VERTEX,
                 ty: wgt::BindingType::Buffer {
-                    ty: wgt::BufferBindingType::Uniform,
-                    has_dynamic_offset: true,
+                    ty: wgt::BufferBindingType::Storage { read_only: true },
+                    has_dynamic_offset: false,
                     min_binding_size: wgt::BufferSize::new(mem::size_of::<Locals>() as _),
                 },
                 count: None,
@@ -265,8 +292,13 @@ impl<A: hal::Api> Example<A> {
                 mapping.ptr.as_ptr(),
                 texture_data.len(),
             );
+            if !mapping.is_coherent {
+                device
+                    .flush_mapped_ranges(&staging_buffer, iter::once(0..staging_buffer_desc.size));
+            }
             device.unmap_buffer(&staging_buffer).unwrap();
-            assert!(mapping.is_coherent);
+            // Labels can also be changed after creation.
+            device.set_buffer_label(&staging_buffer, "stage (logo)");
         }
 
         let texture_desc = hal::TextureDescriptor {
@@ -284,6 +316,7 @@ impl<A: hal::Api> Example<A> {
             memory_flags: hal::MemoryFlags::empty(),
         };
         let texture = unsafe { device.create_texture(&texture_desc).unwrap() };
+        unsafe { device.set_texture_label(&texture, "logo") };
 
         let cmd_encoder_desc = hal::CommandEncoderDescriptor {
             label: None,
@@ -333,29 +366,12 @@ impl<A: hal::Api> Example<A> {
         }
 
         let sampler_desc = hal::SamplerDescriptor {
-            label: None,
-            address_modes: [wgt::AddressMode::ClampToEdge; 3],
             mag_filter: wgt::FilterMode::Linear,
-            min_filter: wgt::FilterMode::Nearest,
-            mipmap_filter: wgt::FilterMode::Nearest,
-            lod_clamp: None,
-            compare: None,
-            anisotropy_clamp: None,
-            border_color: None,
+            ..Default::default()
         };
         let sampler = unsafe { device.create_sampler(&sampler_desc).unwrap() };
 
-        let globals = Globals {
-            // cgmath::ortho() projection
-            mvp: [
-                [2.0 / window_size.0 as f32, 0.0, 0.0, 0.0],
-                [0.0, 2.0 / window_size.1 as f32, 0.0, 0.0],
-                [0.0, 0.0, 1.0, 0.0],
-                [-1.0, -1.0, 0.0, 1.0],
-            ],
-            size: [BUNNY_SIZE; 2],
-            pad: [0.0; 2],
-        };
+        let globals = Globals::new(window_size.0, window_size.1);
 
         let global_buffer_desc = hal::BufferDescriptor {
             label: Some("global"),
@@ -373,19 +389,18 @@ impl<A: hal::Api> Example<A> {
                 mapping.ptr.as_ptr(),
                 mem::size_of::<Globals>(),
             );
+            if !mapping.is_coherent {
+                device.flush_mapped_ranges(&buffer, iter::once(0..global_buffer_desc.size));
+            }
             device.unmap_buffer(&buffer).unwrap();
-            assert!(mapping.is_coherent);
             buffer
         };
 
-        let local_alignment = hal::auxil::align_to(
-            mem::size_of::<Locals>() as u32,
-            capabilities.limits.min_uniform_buffer_offset_alignment,
-        );
+        // All the bunnies live in a single storage buffer, indexed by the instance index.
         let local_buffer_desc = hal::BufferDescriptor {
             label: Some("local"),
-            size: (MAX_BUNNIES as wgt::BufferAddress) * (local_alignment as wgt::BufferAddress),
-            usage: hal::BufferUses::MAP_WRITE | hal::BufferUses::UNIFORM,
+            size: (MAX_BUNNIES * mem::size_of::<Locals>()) as wgt::BufferAddress,
+            usage: hal::BufferUses::MAP_WRITE | hal::BufferUses::STORAGE_READ,
             memory_flags: hal::MemoryFlags::PREFER_COHERENT,
         };
         let local_buffer = unsafe { device.create_buffer(&local_buffer_desc).unwrap() };
@@ -398,6 +413,7 @@ impl<A: hal::Api> Example<A> {
             range: wgt::ImageSubresourceRange::default(),
         };
         let texture_view = unsafe { device.create_texture_view(&texture, &view_desc).unwrap() };
+        unsafe { device.set_texture_view_label(&texture_view, "logo") };
 
         let global_group = {
             let global_buffer_binding = hal::BufferBinding {
@@ -440,7 +456,7 @@ impl<A: hal::Api> Example<A> {
             let local_buffer_binding = hal::BufferBinding {
                 buffer: &local_buffer,
                 offset: 0,
-                size: wgt::BufferSize::new(mem::size_of::<Locals>() as _),
+                size: None,
             };
             let local_group_desc = hal::BindGroupDescriptor {
                 label: Some("local"),
@@ -473,7 +489,9 @@ impl<A: hal::Api> Example<A> {
         Ok(Example {
             instance,
             surface,
-            surface_format: surface_config.format,
+            surface_config,
+            present_modes: surface_caps.present_modes,
+            surface_extents: surface_caps.extents,
             adapter,
             device,
             queue,
@@ -486,7 +504,6 @@ impl<A: hal::Api> Example<A> {
             local_group_layout,
             bunnies: Vec::new(),
             local_buffer,
-            local_alignment,
             global_buffer,
             sampler,
             texture,
@@ -546,6 +563,40 @@ impl<A: hal::Api> Example<A> {
         }
     }
 
+    fn resize(&mut self, width: u32, height: u32) -> Result<(), hal::SurfaceError> {
+        // A minimized window reports a zero size, which can't be configured.
+        if width == 0 || height == 0 {
+            return Ok(());
+        }
+        let (min, max) = (self.surface_extents.start(), self.surface_extents.end());
+        let width = width.max(min.width).min(max.width);
+        let height = height.max(min.height).min(max.height);
+        self.surface_config.extent.width = width;
+        self.surface_config.extent.height = height;
+        unsafe {
+            self.surface.configure(&self.device, &self.surface_config)?;
+        }
+        self.extent = [width, height];
+
+        // The projection depends on the extent, so the globals need to be uploaded again.
+        let globals = Globals::new(width, height);
+        let size = mem::size_of::<Globals>() as wgt::BufferAddress;
+        unsafe {
+            let mapping = self.device.map_buffer(&self.global_buffer, 0..size)?;
+            ptr::copy_nonoverlapping(
+                &globals as *const Globals as *const u8,
+                mapping.ptr.as_ptr(),
+                mem::size_of::<Globals>(),
+            );
+            if !mapping.is_coherent {
+                self.device
+                    .flush_mapped_ranges(&self.global_buffer, iter::once(0..size));
+            }
+            self.device.unmap_buffer(&self.global_buffer)?;
+        }
+        Ok(())
+    }
+
     fn update(&mut self, event: winit::event::WindowEvent) {
         if let winit::event::WindowEvent::KeyboardInput {
             input:
@@ -576,6 +627,33 @@ impl<A: hal::Api> Example<A> {
                 });
             }
         }
+
+        if let winit::event::WindowEvent::KeyboardInput {
+            input:
+                winit::event::KeyboardInput {
+                    virtual_keycode: Some(winit::event::VirtualKeyCode::V),
+                    state: winit::event::ElementState::Pressed,
+                    ..
+                },
+            ..
+        } = event
+        {
+            // Cycle through the present modes reported by the surface capabilities,
+            // re-applying the rest of the configuration as is.
+            let current = self
+                .present_modes
+                .iter()
+                .position(|&mode| mode == self.surface_config.present_mode)
+                .unwrap_or(0);
+            let present_mode = self.present_modes[(current + 1) % self.present_modes.len()];
+            println!("Switching to present mode {:?}", present_mode);
+            self.surface_config.present_mode = present_mode;
+            unsafe {
+                self.surface
+                    .configure(&self.device, &self.surface_config)
+                    .unwrap();
+            }
+        }
     }
 
     fn render(&mut self) {
@@ -596,7 +674,7 @@ impl<A: hal::Api> Example<A> {
         }
 
         if !self.bunnies.is_empty() {
-            let size = self.bunnies.len() * self.local_alignment as usize;
+            let size = self.bunnies.len() * mem::size_of::<Locals>();
             unsafe {
                 let mapping = self
                     .device
@@ -607,14 +685,31 @@ impl<A: hal::Api> Example<A> {
                     mapping.ptr.as_ptr(),
                     size,
                 );
-                assert!(mapping.is_coherent);
+                if !mapping.is_coherent {
+                    self.device.flush_mapped_ranges(
+                        &self.local_buffer,
+                        iter::once(0..size as wgt::BufferAddress),
+                    );
+                }
                 self.device.unmap_buffer(&self.local_buffer).unwrap();
             }
         }
 
         let ctx = &mut self.contexts[self.context_index];
 
-        let surface_tex = unsafe { self.surface.acquire_texture(None).unwrap().unwrap().texture };
+        let (surface_tex, suboptimal) = match unsafe { self.surface.acquire_texture(None) } {
+            Ok(ast) => (ast.texture, ast.suboptimal),
+            Err(hal::SurfaceError::Timeout) => return,
+            Err(hal::SurfaceError::Outdated) => {
+                unsafe {
+                    self.surface
+                        .configure(&self.device, &self.surface_config)
+                        .unwrap()
+                };
+                return;
+            }
+            Err(err) => panic!("Unable to acquire a surface texture: {}", err),
+        };
 
         let target_barrier0 = hal::TextureBarrier {
             texture: surface_tex.borrow(),
@@ -628,7 +723,7 @@ impl<A: hal::Api> Example<A> {
 
         let surface_view_desc = hal::TextureViewDescriptor {
             label: None,
-            format: self.surface_format,
+            format: self.surface_config.format,
             dimension: wgt::TextureViewDimension::D2,
             usage: hal::TextureUses::COLOR_TARGET,
             range: wgt::ImageSubresourceRange::default(),
@@ -640,11 +735,7 @@ impl<A: hal::Api> Example<A> {
         };
         let pass_desc = hal::RenderPassDescriptor {
             label: None,
-            extent: wgt::Extent3d {
-                width: self.extent[0],
-                height: self.extent[1],
-                depth_or_array_layers: 1,
-            },
+            extent: self.surface_config.extent,
             sample_count: 1,
             color_attachments: &[Some(hal::ColorAttachment {
                 target: hal::Attachment {
@@ -662,21 +753,16 @@ impl<A: hal::Api> Example<A> {
             })],
             depth_stencil_attachment: None,
             multiview: None,
+            occlusion_query_set: None,
         };
         unsafe {
             ctx.encoder.begin_render_pass(&pass_desc);
             ctx.encoder.set_render_pipeline(&self.pipeline);
             ctx.encoder
                 .set_bind_group(&self.pipeline_layout, 0, &self.global_group, &[]);
-        }
-
-        for i in 0..self.bunnies.len() {
-            let offset = (i as wgt::DynamicOffset) * (self.local_alignment as wgt::DynamicOffset);
-            unsafe {
-                ctx.encoder
-                    .set_bind_group(&self.pipeline_layout, 1, &self.local_group, &[offset]);
-                ctx.encoder.draw(0, 4, 0, 1);
-            }
+            ctx.encoder
+                .set_bind_group(&self.pipeline_layout, 1, &self.local_group, &[]);
+            ctx.encoder.draw(0, 4, 0, self.bunnies.len() as u32);
         }
 
         ctx.frames_recorded += 1;
@@ -705,6 +791,14 @@ impl<A: hal::Api> Example<A> {
             ctx.used_views.push(surface_tex_view);
         };
 
+        if suboptimal {
+            unsafe {
+                self.surface
+                    .configure(&self.device, &self.surface_config)
+                    .unwrap();
+            }
+        }
+
         if do_fence {
             log::info!("Context switch from {}", self.context_index);
             let old_fence_value = ctx.fence_value;
@@ -790,6 +884,11 @@ fn main() {
                 | winit::event::WindowEvent::CloseRequested => {
                     *control_flow = winit::event_loop::ControlFlow::Exit;
                 }
+                winit::event::WindowEvent::Resized(size) => {
+                    if let Err(err) = example.as_mut().unwrap().resize(size.width, size.height) {
+                        log::error!("Unable to resize the surface: {}", err);
+                    }
+                }
                 _ => {
                     example.as_mut().unwrap().update(event);
                 }
@@ -15,7 +15,7 @@ var<uniform> globals: Globals;
 
 @group(1)
 @binding(0)
-var<uniform> locals: Locals;
+var<storage, read> locals: array<Locals>;
 
 struct VertexOutput {
     @builtin(position) position: vec4<f32>,
@@ -24,11 +24,15 @@ struct VertexOutput {
 };
 
 @vertex
-fn vs_main(@builtin(vertex_index) vi: u32) -> VertexOutput {
+fn vs_main(
+    @builtin(vertex_index) vi: u32,
+    @builtin(instance_index) ii: u32,
+) -> VertexOutput {
+    let local = locals[ii];
     let tc = vec2<f32>(f32(vi & 1u), 0.5 * f32(vi & 2u));
     let offset = vec2<f32>(tc.x * globals.size.x, tc.y * globals.size.y);
-    let pos = globals.mvp * vec4<f32>(locals.position + offset, 0.0, 1.0);
-    let color = vec4<f32>((vec4<u32>(locals.color) >> vec4<u32>(0u, 8u, 16u, 24u)) & vec4<u32>(255u)) / 255.0;
+    let pos = globals.mvp * vec4<f32>(local.position + offset, 0.0, 1.0);
+    let color = vec4<f32>((vec4<u32>(local.color) >> vec4<u32>(0u, 8u, 16u, 24u)) & vec4<u32>(255u)) / 255.0;
     return VertexOutput(pos, tc, color);
 }
 
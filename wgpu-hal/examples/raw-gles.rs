@@ -174,6 +174,7 @@ fn fill_screen(exposed: &hal::ExposedAdapter<hal::api::Gles>, width: u32, height
         })],
         depth_stencil_attachment: None,
         multiview: None,
+        occlusion_query_set: None,
     };
     unsafe {
         encoder.begin_encoding(None).unwrap();
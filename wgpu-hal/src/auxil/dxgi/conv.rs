@@ -93,16 +93,23 @@ pub fn map_texture_format_nosrgb(format: wgt::TextureFormat) -> dxgiformat::DXGI
 }
 
 //Note: SRV and UAV can't use the depth formats directly
-//TODO: stencil views?
-pub fn map_texture_format_nodepth(format: wgt::TextureFormat) -> dxgiformat::DXGI_FORMAT {
-    match format {
-        wgt::TextureFormat::Depth32Float => dxgiformat::DXGI_FORMAT_R32_FLOAT,
-        wgt::TextureFormat::Depth32FloatStencil8 => {
-            dxgiformat::DXGI_FORMAT_R32_FLOAT_X8X24_TYPELESS
+pub fn map_texture_format_nodepth(
+    format: wgt::TextureFormat,
+    aspect: wgt::TextureAspect,
+) -> dxgiformat::DXGI_FORMAT {
+    use wgt::{TextureAspect as Ta, TextureFormat as Tf};
+    match (format, aspect) {
+        (Tf::Depth32FloatStencil8, Ta::StencilOnly) => {
+            dxgiformat::DXGI_FORMAT_X32_TYPELESS_G8X24_UINT
+        }
+        (Tf::Depth24PlusStencil8 | Tf::Depth24UnormStencil8, Ta::StencilOnly) => {
+            dxgiformat::DXGI_FORMAT_X24_TYPELESS_G8_UINT
+        }
+        (Tf::Depth32Float, _) => dxgiformat::DXGI_FORMAT_R32_FLOAT,
+        (Tf::Depth32FloatStencil8, _) => dxgiformat::DXGI_FORMAT_R32_FLOAT_X8X24_TYPELESS,
+        (Tf::Depth24Plus | Tf::Depth24PlusStencil8 | Tf::Depth24UnormStencil8, _) => {
+            dxgiformat::DXGI_FORMAT_R24_UNORM_X8_TYPELESS
         }
-        wgt::TextureFormat::Depth24Plus
-        | wgt::TextureFormat::Depth24PlusStencil8
-        | wgt::TextureFormat::Depth24UnormStencil8 => dxgiformat::DXGI_FORMAT_R24_UNORM_X8_TYPELESS,
         _ => {
             assert_eq!(
                 crate::FormatAspects::from(format),
@@ -69,6 +69,12 @@ impl crate::Device<super::Api> for super::Device {
         todo!()
     }
 
+    unsafe fn set_buffer_label(&self, _buffer: &super::Buffer, _label: &str) {}
+
+    unsafe fn set_texture_label(&self, _texture: &super::Texture, _label: &str) {}
+
+    unsafe fn set_texture_view_label(&self, _view: &super::TextureView, _label: &str) {}
+
     unsafe fn create_sampler(
         &self,
         desc: &crate::SamplerDescriptor,
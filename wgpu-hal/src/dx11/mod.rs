@@ -125,7 +125,7 @@ impl crate::Surface<Api> for Surface {
     unsafe fn acquire_texture(
         &mut self,
         _timeout: Option<std::time::Duration>,
-    ) -> Result<Option<crate::AcquiredSurfaceTexture<Api>>, crate::SurfaceError> {
+    ) -> Result<crate::AcquiredSurfaceTexture<Api>, crate::SurfaceError> {
         todo!()
     }
 
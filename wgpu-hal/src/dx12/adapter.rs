@@ -5,7 +5,8 @@ use crate::{
 use std::{mem, sync::Arc, thread};
 use winapi::{
     shared::{dxgi, dxgi1_2, windef, winerror},
-    um::{d3d12, d3d12sdklayers, winuser},
+    um::{d3d12, d3d12sdklayers, winnt, winuser},
+    Interface as _,
 };
 
 impl Drop for super::Adapter {
@@ -85,6 +86,28 @@ impl super::Adapter {
             name.to_string_lossy().into_owned()
         };
 
+        // DXGI doesn't name the driver, but it does report the user mode driver version.
+        let driver_info = {
+            let mut umd_version: winnt::LARGE_INTEGER = unsafe { mem::zeroed() };
+            let hr = unsafe {
+                adapter
+                    .unwrap_adapter2()
+                    .CheckInterfaceSupport(&dxgi::IDXGIDevice::uuidof(), &mut umd_version)
+            };
+            if winerror::SUCCEEDED(hr) {
+                let version = unsafe { *umd_version.QuadPart() } as u64;
+                format!(
+                    "{}.{}.{}.{}",
+                    version >> 48,
+                    (version >> 32) & 0xFFFF,
+                    (version >> 16) & 0xFFFF,
+                    version & 0xFFFF
+                )
+            } else {
+                String::new()
+            }
+        };
+
         let mut features_architecture: d3d12::D3D12_FEATURE_DATA_ARCHITECTURE =
             unsafe { mem::zeroed() };
         assert_eq!(0, unsafe {
@@ -122,6 +145,8 @@ impl super::Adapter {
             } else {
                 wgt::DeviceType::DiscreteGpu
             },
+            driver: String::new(),
+            driver_info,
         };
 
         let mut options: d3d12::D3D12_FEATURE_DATA_D3D12_OPTIONS = unsafe { mem::zeroed() };
@@ -115,6 +115,10 @@ impl super::CommandEncoder {
     }
 
     fn prepare_dispatch(&mut self, count: [u32; 3]) {
+        debug_assert!(
+            matches!(self.pass.kind, super::PassKind::Compute),
+            "dispatch outside of a compute pass"
+        );
         if let Some(root_index) = self.pass.layout.special_constants_root_index {
             let needs_update = match self.pass.root_elements[root_index as usize] {
                 super::RootElement::SpecialConstantBuffer {
@@ -612,6 +612,18 @@ impl crate::Device<super::Api> for super::Device {
         }
     }
 
+    unsafe fn set_buffer_label(&self, buffer: &super::Buffer, label: &str) {
+        let cwstr = conv::map_label(label);
+        buffer.resource.SetName(cwstr.as_ptr());
+    }
+    unsafe fn set_texture_label(&self, texture: &super::Texture, label: &str) {
+        let cwstr = conv::map_label(label);
+        texture.resource.SetName(cwstr.as_ptr());
+    }
+    unsafe fn set_texture_view_label(&self, _view: &super::TextureView, _label: &str) {
+        // Views are plain descriptors, there is no object to name.
+    }
+
     unsafe fn create_sampler(
         &self,
         desc: &crate::SamplerDescriptor,
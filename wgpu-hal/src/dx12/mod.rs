@@ -359,6 +359,20 @@ pub struct Buffer {
 unsafe impl Send for Buffer {}
 unsafe impl Sync for Buffer {}
 
+impl Buffer {
+    /// Returns the underlying `ID3D12Resource` of the buffer.
+    ///
+    /// The returned pointer isn't `AddRef`'d.
+    ///
+    /// # Safety
+    ///
+    /// - The resource must not be used after the buffer is destroyed
+    /// - The resource must not be released externally
+    pub unsafe fn raw_handle(&self) -> native::Resource {
+        self.resource
+    }
+}
+
 impl crate::BufferBinding<'_, Api> {
     fn resolve_size(&self) -> wgt::BufferAddress {
         match self.size {
@@ -386,6 +400,18 @@ unsafe impl Send for Texture {}
 unsafe impl Sync for Texture {}
 
 impl Texture {
+    /// Returns the underlying `ID3D12Resource` of the texture.
+    ///
+    /// The returned pointer isn't `AddRef`'d.
+    ///
+    /// # Safety
+    ///
+    /// - The resource must not be used after the texture is destroyed
+    /// - The resource must not be released externally
+    pub unsafe fn raw_handle(&self) -> native::Resource {
+        self.resource
+    }
+
     fn array_layer_count(&self) -> u32 {
         match self.dimension {
             wgt::TextureDimension::D1 | wgt::TextureDimension::D2 => {
@@ -714,10 +740,12 @@ impl crate::Surface<Api> for Surface {
     unsafe fn acquire_texture(
         &mut self,
         timeout: Option<std::time::Duration>,
-    ) -> Result<Option<crate::AcquiredSurfaceTexture<Api>>, crate::SurfaceError> {
+    ) -> Result<crate::AcquiredSurfaceTexture<Api>, crate::SurfaceError> {
         let sc = self.swap_chain.as_mut().unwrap();
 
-        sc.wait(timeout)?;
+        if !sc.wait(timeout)? {
+            return Err(crate::SurfaceError::Timeout);
+        }
 
         let base_index = sc.raw.GetCurrentBackBufferIndex() as usize;
         let index = (base_index + sc.acquired_count) % sc.resources.len();
@@ -731,10 +759,10 @@ impl crate::Surface<Api> for Surface {
             mip_level_count: 1,
             sample_count: 1,
         };
-        Ok(Some(crate::AcquiredSurfaceTexture {
+        Ok(crate::AcquiredSurfaceTexture {
             texture,
             suboptimal: false,
-        }))
+        })
     }
     unsafe fn discard_texture(&mut self, _texture: Texture) {
         let sc = self.swap_chain.as_mut().unwrap();
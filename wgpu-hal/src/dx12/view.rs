@@ -3,11 +3,15 @@ use std::mem;
 use winapi::um::d3d12;
 
 pub(crate) const D3D12_DEFAULT_SHADER_4_COMPONENT_MAPPING: u32 = 0x1688;
+// Stencil is read from the second component of the stencil plane, so move it
+// into the first one and fill the rest with (0, 0, 1).
+const D3D12_STENCIL_SHADER_4_COMPONENT_MAPPING: u32 = 0x1B21;
 
 pub(super) struct ViewDescriptor {
     dimension: wgt::TextureViewDimension,
     pub format: native::Format,
     format_nodepth: native::Format,
+    plane_slice: u32,
     multisampled: bool,
     array_layer_base: u32,
     array_layer_count: u32,
@@ -20,7 +24,14 @@ impl crate::TextureViewDescriptor<'_> {
         ViewDescriptor {
             dimension: self.dimension,
             format: auxil::dxgi::conv::map_texture_format(self.format),
-            format_nodepth: auxil::dxgi::conv::map_texture_format_nodepth(self.format),
+            format_nodepth: auxil::dxgi::conv::map_texture_format_nodepth(
+                self.format,
+                self.range.aspect,
+            ),
+            plane_slice: match self.range.aspect {
+                wgt::TextureAspect::StencilOnly => 1,
+                _ => 0,
+            },
             multisampled: texture.sample_count > 1,
             mip_level_base: self.range.base_mip_level,
             mip_level_count: match self.range.mip_level_count {
@@ -41,7 +52,11 @@ impl ViewDescriptor {
         let mut desc = d3d12::D3D12_SHADER_RESOURCE_VIEW_DESC {
             Format: self.format_nodepth,
             ViewDimension: 0,
-            Shader4ComponentMapping: D3D12_DEFAULT_SHADER_4_COMPONENT_MAPPING,
+            Shader4ComponentMapping: if self.plane_slice == 1 {
+                D3D12_STENCIL_SHADER_4_COMPONENT_MAPPING
+            } else {
+                D3D12_DEFAULT_SHADER_4_COMPONENT_MAPPING
+            },
             u: mem::zeroed(),
         };
 
@@ -76,7 +91,7 @@ impl ViewDescriptor {
                 *desc.u.Texture2D_mut() = d3d12::D3D12_TEX2D_SRV {
                     MostDetailedMip: self.mip_level_base,
                     MipLevels: self.mip_level_count,
-                    PlaneSlice: 0,
+                    PlaneSlice: self.plane_slice,
                     ResourceMinLODClamp: 0.0,
                 }
             }
@@ -96,7 +111,7 @@ impl ViewDescriptor {
                     MipLevels: self.mip_level_count,
                     FirstArraySlice: self.array_layer_base,
                     ArraySize: self.array_layer_count,
-                    PlaneSlice: 0,
+                    PlaneSlice: self.plane_slice,
                     ResourceMinLODClamp: 0.0,
                 }
             }
@@ -67,8 +67,8 @@ impl crate::Surface<Api> for Context {
     unsafe fn acquire_texture(
         &mut self,
         timeout: Option<std::time::Duration>,
-    ) -> Result<Option<crate::AcquiredSurfaceTexture<Api>>, crate::SurfaceError> {
-        Ok(None)
+    ) -> Result<crate::AcquiredSurfaceTexture<Api>, crate::SurfaceError> {
+        Err(crate::SurfaceError::Timeout)
     }
     unsafe fn discard_texture(&mut self, texture: Resource) {}
 }
@@ -144,6 +144,9 @@ impl crate::Device<Api> for Context {
         Ok(Resource)
     }
     unsafe fn destroy_texture_view(&self, view: Resource) {}
+    unsafe fn set_buffer_label(&self, buffer: &Resource, label: &str) {}
+    unsafe fn set_texture_label(&self, texture: &Resource, label: &str) {}
+    unsafe fn set_texture_view_label(&self, view: &Resource, label: &str) {}
     unsafe fn create_sampler(&self, desc: &crate::SamplerDescriptor) -> DeviceResult<Resource> {
         Ok(Resource)
     }
@@ -91,7 +91,7 @@ impl super::Adapter {
         }
     }
 
-    fn make_info(vendor_orig: String, renderer_orig: String) -> wgt::AdapterInfo {
+    fn make_info(vendor_orig: String, renderer_orig: String, version: String) -> wgt::AdapterInfo {
         let vendor = vendor_orig.to_lowercase();
         let renderer = renderer_orig.to_lowercase();
 
@@ -162,6 +162,8 @@ impl super::Adapter {
             vendor: vendor_id,
             device: 0,
             device_type: inferred_device_type,
+            driver: String::new(),
+            driver_info: version,
             backend: wgt::Backend::Gl,
         }
     }
@@ -299,6 +301,7 @@ impl super::Adapter {
             wgt::DownlevelFlags::BUFFER_BINDINGS_NOT_16_BYTE_ALIGNED,
             !(cfg!(target_arch = "wasm32") || is_angle),
         );
+        downlevel_flags.set(wgt::DownlevelFlags::STENCIL_TEXTURE_VIEWS, ver >= (3, 1));
 
         let mut features = wgt::Features::empty()
             | wgt::Features::TEXTURE_ADAPTER_SPECIFIC_FORMAT_FEATURES
@@ -362,6 +365,10 @@ impl super::Adapter {
             extensions.contains("GL_EXT_texture_shadow_lod"),
         );
         private_caps.set(super::PrivateCapabilities::MEMORY_BARRIERS, ver >= (3, 1));
+        private_caps.set(
+            super::PrivateCapabilities::DEPTH_STENCIL_TEXTURE_MODE,
+            ver >= (3, 1),
+        );
         private_caps.set(
             super::PrivateCapabilities::VERTEX_BUFFER_LAYOUT,
             ver >= (3, 1),
@@ -524,7 +531,7 @@ impl super::Adapter {
                     max_texture_size,
                 }),
             },
-            info: Self::make_info(vendor, renderer),
+            info: Self::make_info(vendor, renderer, version),
             features,
             capabilities: crate::Capabilities {
                 limits,
@@ -714,6 +714,36 @@ impl crate::Device<super::Api> for super::Device {
     }
     unsafe fn destroy_texture_view(&self, _view: super::TextureView) {}
 
+    #[cfg_attr(target_arch = "wasm32", allow(unused))]
+    unsafe fn set_buffer_label(&self, buffer: &super::Buffer, label: &str) {
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(raw) = buffer.raw {
+            let gl = &self.shared.context.lock();
+            if gl.supports_debug() {
+                gl.object_label(glow::BUFFER, mem::transmute(raw), Some(label));
+            }
+        }
+    }
+    #[cfg_attr(target_arch = "wasm32", allow(unused))]
+    unsafe fn set_texture_label(&self, texture: &super::Texture, label: &str) {
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let gl = &self.shared.context.lock();
+            if gl.supports_debug() {
+                match texture.inner {
+                    super::TextureInner::Renderbuffer { raw } => {
+                        gl.object_label(glow::RENDERBUFFER, mem::transmute(raw), Some(label));
+                    }
+                    super::TextureInner::Texture { raw, .. } => {
+                        gl.object_label(glow::TEXTURE, mem::transmute(raw), Some(label));
+                    }
+                    super::TextureInner::DefaultRenderbuffer => {}
+                }
+            }
+        }
+    }
+    unsafe fn set_texture_view_label(&self, _view: &super::TextureView, _label: &str) {}
+
     unsafe fn create_sampler(
         &self,
         desc: &crate::SamplerDescriptor,
@@ -919,7 +949,11 @@ impl crate::Device<super::Api> for super::Device {
                             "This is an implementation problem of wgpu-hal/gles backend.")
                     }
                     let (raw, target) = view.inner.as_native();
-                    super::RawBinding::Texture { raw, target }
+                    super::RawBinding::Texture {
+                        raw,
+                        target,
+                        aspects: view.aspects,
+                    }
                 }
                 wgt::BindingType::StorageTexture {
                     access,
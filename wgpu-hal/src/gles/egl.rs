@@ -1195,7 +1195,7 @@ impl crate::Surface<super::Api> for Surface {
     unsafe fn acquire_texture(
         &mut self,
         _timeout_ms: Option<Duration>, //TODO
-    ) -> Result<Option<crate::AcquiredSurfaceTexture<super::Api>>, crate::SurfaceError> {
+    ) -> Result<crate::AcquiredSurfaceTexture<super::Api>, crate::SurfaceError> {
         let sc = self.swapchain.as_ref().unwrap();
         let texture = super::Texture {
             inner: super::TextureInner::Renderbuffer {
@@ -1212,10 +1212,10 @@ impl crate::Surface<super::Api> for Surface {
             },
             is_cubemap: false,
         };
-        Ok(Some(crate::AcquiredSurfaceTexture {
+        Ok(crate::AcquiredSurfaceTexture {
             texture,
             suboptimal: false,
-        }))
+        })
     }
     unsafe fn discard_texture(&mut self, _texture: super::Texture) {}
 }
@@ -142,6 +142,8 @@ bitflags::bitflags! {
         const COLOR_BUFFER_HALF_FLOAT = 1 << 8;
         /// Supports `f11/f10` and `f32` color buffers
         const COLOR_BUFFER_FLOAT = 1 << 9;
+        /// Supports picking the sampled aspect of depth-stencil textures with `GL_DEPTH_STENCIL_TEXTURE_MODE`
+        const DEPTH_STENCIL_TEXTURE_MODE = 1 << 10;
     }
 }
 
@@ -350,6 +352,7 @@ enum RawBinding {
     Texture {
         raw: glow::Texture,
         target: BindTarget,
+        aspects: crate::FormatAspects,
         //TODO: mip levels, array layers
     },
     Image(ImageBinding),
@@ -730,6 +733,7 @@ enum Command {
         slot: u32,
         texture: glow::Texture,
         target: BindTarget,
+        aspects: crate::FormatAspects,
     },
     BindImage {
         slot: u32,
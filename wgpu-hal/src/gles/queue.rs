@@ -1085,9 +1085,30 @@ impl super::Queue {
                 slot,
                 texture,
                 target,
+                aspects,
             } => {
                 gl.active_texture(glow::TEXTURE0 + slot);
                 gl.bind_texture(target, Some(texture));
+
+                // The sampled aspect is a property of the texture, so it has to be
+                // set again every time a view of a depth-stencil texture is bound.
+                if self
+                    .shared
+                    .private_caps
+                    .contains(super::PrivateCapabilities::DEPTH_STENCIL_TEXTURE_MODE)
+                {
+                    // Views of both aspects sample depth, like any non-GLES backend.
+                    let mode = if aspects == crate::FormatAspects::STENCIL {
+                        Some(glow::STENCIL_INDEX)
+                    } else if aspects.contains(crate::FormatAspects::DEPTH) {
+                        Some(glow::DEPTH_COMPONENT)
+                    } else {
+                        None
+                    };
+                    if let Some(mode) = mode {
+                        gl.tex_parameter_i32(target, glow::DEPTH_STENCIL_TEXTURE_MODE, mode as i32);
+                    }
+                }
             }
             C::BindImage { slot, ref binding } => {
                 gl.bind_image_texture(
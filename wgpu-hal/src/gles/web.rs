@@ -287,7 +287,7 @@ impl crate::Surface<super::Api> for Surface {
     unsafe fn acquire_texture(
         &mut self,
         _timeout_ms: Option<std::time::Duration>, //TODO
-    ) -> Result<Option<crate::AcquiredSurfaceTexture<super::Api>>, crate::SurfaceError> {
+    ) -> Result<crate::AcquiredSurfaceTexture<super::Api>, crate::SurfaceError> {
         let sc = self.swapchain.as_ref().unwrap();
         let texture = super::Texture {
             inner: super::TextureInner::Texture {
@@ -305,10 +305,10 @@ impl crate::Surface<super::Api> for Surface {
             },
             is_cubemap: false,
         };
-        Ok(Some(crate::AcquiredSurfaceTexture {
+        Ok(crate::AcquiredSurfaceTexture {
             texture,
             suboptimal: false,
-        }))
+        })
     }
 
     unsafe fn discard_texture(&mut self, _texture: super::Texture) {}
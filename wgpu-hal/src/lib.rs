@@ -138,6 +138,8 @@ pub enum SurfaceError {
     Lost,
     #[error("surface is outdated, needs to be re-created")]
     Outdated,
+    #[error("timed out waiting for a surface texture")]
+    Timeout,
     #[error(transparent)]
     Device(#[from] DeviceError),
     #[error("other reason: {0}")]
@@ -185,6 +187,15 @@ pub trait Instance<A: Api>: Sized + Send + Sync {
 }
 
 pub trait Surface<A: Api>: Send + Sync {
+    /// Configures the surface for presentation.
+    ///
+    /// The surface doesn't keep the configuration around for the caller.
+    /// Handling a resize is done by calling this again with the previous
+    /// configuration and an updated `extent`. Backends reuse the existing
+    /// swapchain where they can when the surface is already configured.
+    ///
+    /// Valid usage:
+    /// - no surface texture is currently acquired
     unsafe fn configure(
         &mut self,
         device: &A::Device,
@@ -202,15 +213,22 @@ pub trait Surface<A: Api>: Send + Sync {
     /// Some backends can't support a timeout when acquiring a texture and
     /// the timeout will be ignored.
     ///
-    /// Returns `None` on timing out.
+    /// Returns `SurfaceError::Timeout` on timing out.
+    /// `SurfaceError::Outdated` means the surface needs to be configured again,
+    /// while `SurfaceError::Lost` means it has to be re-created.
     unsafe fn acquire_texture(
         &mut self,
         timeout: Option<std::time::Duration>,
-    ) -> Result<Option<AcquiredSurfaceTexture<A>>, SurfaceError>;
+    ) -> Result<AcquiredSurfaceTexture<A>, SurfaceError>;
     unsafe fn discard_texture(&mut self, texture: A::SurfaceTexture);
 }
 
 pub trait Adapter<A: Api>: Send + Sync {
+    /// Opens a logical device with the given features and limits.
+    ///
+    /// Valid usage:
+    /// - `features` is a subset of `ExposedAdapter::features`
+    /// - `limits` are within `ExposedAdapter::capabilities.limits`
     unsafe fn open(
         &self,
         features: wgt::Features,
@@ -229,6 +247,12 @@ pub trait Adapter<A: Api>: Send + Sync {
     unsafe fn surface_capabilities(&self, surface: &A::Surface) -> Option<SurfaceCapabilities>;
 }
 
+/// A logical device.
+///
+/// The device is `Send + Sync` and its methods take `&self`, so resources and
+/// pipelines can be created from several threads at once, for example to compile
+/// pipelines on worker threads while loading assets. Backends that can't do that
+/// natively serialize access internally, e.g. GL holds its context lock for each call.
 pub trait Device<A: Api>: Send + Sync {
     /// Exit connection to this logical device.
     unsafe fn exit(self, queue: A::Queue);
@@ -237,6 +261,12 @@ pub trait Device<A: Api>: Send + Sync {
     /// The initial usage is `BufferUses::empty()`.
     unsafe fn create_buffer(&self, desc: &BufferDescriptor) -> Result<A::Buffer, DeviceError>;
     unsafe fn destroy_buffer(&self, buffer: A::Buffer);
+    /// Maps the given range of a buffer into host memory.
+    ///
+    /// Valid usage:
+    /// - `buffer` was created with `BufferUses::MAP_READ` or `BufferUses::MAP_WRITE`
+    /// - `range` lies within the buffer size
+    /// - `buffer` is not already mapped
     //TODO: clarify if zero-sized mapping is allowed
     unsafe fn map_buffer(
         &self,
@@ -244,9 +274,25 @@ pub trait Device<A: Api>: Send + Sync {
         range: MemoryRange,
     ) -> Result<BufferMapping, DeviceError>;
     unsafe fn unmap_buffer(&self, buffer: &A::Buffer) -> Result<(), DeviceError>;
+    /// Makes host writes to the given ranges of a mapped buffer visible to the device.
+    ///
+    /// This is only needed when the mapping is not coherent,
+    /// and is a no-op on coherent memory.
+    ///
+    /// Valid usage:
+    /// - `buffer` is currently mapped
+    /// - every range lies within the range passed to `map_buffer`
     unsafe fn flush_mapped_ranges<I>(&self, buffer: &A::Buffer, ranges: I)
     where
         I: Iterator<Item = MemoryRange>;
+    /// Makes device writes to the given ranges of a mapped buffer visible to the host.
+    ///
+    /// This is only needed when the mapping is not coherent,
+    /// and is a no-op on coherent memory.
+    ///
+    /// Valid usage:
+    /// - `buffer` is currently mapped
+    /// - every range lies within the range passed to `map_buffer`
     unsafe fn invalidate_mapped_ranges<I>(&self, buffer: &A::Buffer, ranges: I)
     where
         I: Iterator<Item = MemoryRange>;
@@ -262,6 +308,16 @@ pub trait Device<A: Api>: Send + Sync {
         desc: &TextureViewDescriptor,
     ) -> Result<A::TextureView, DeviceError>;
     unsafe fn destroy_texture_view(&self, view: A::TextureView);
+    /// Updates the debug label of an existing buffer.
+    ///
+    /// This is a no-op when the backend has no debug naming support enabled.
+    unsafe fn set_buffer_label(&self, buffer: &A::Buffer, label: &str);
+    /// Updates the debug label of an existing texture.
+    unsafe fn set_texture_label(&self, texture: &A::Texture, label: &str);
+    /// Updates the debug label of an existing texture view.
+    ///
+    /// Backends where views aren't standalone objects ignore this.
+    unsafe fn set_texture_view_label(&self, view: &A::TextureView, label: &str);
     unsafe fn create_sampler(&self, desc: &SamplerDescriptor) -> Result<A::Sampler, DeviceError>;
     unsafe fn destroy_sampler(&self, sampler: A::Sampler);
 
@@ -312,6 +368,10 @@ pub trait Device<A: Api>: Send + Sync {
     unsafe fn destroy_query_set(&self, set: A::QuerySet);
     unsafe fn create_fence(&self) -> Result<A::Fence, DeviceError>;
     unsafe fn destroy_fence(&self, fence: A::Fence);
+    /// Returns the latest value the fence has reached, without blocking.
+    ///
+    /// Fence values only grow, so any submission signalled with a value
+    /// less than or equal to the result has completed on the GPU.
     unsafe fn get_fence_value(&self, fence: &A::Fence) -> Result<FenceValue, DeviceError>;
     /// Calling wait with a lower value than the current fence value will immediately return.
     unsafe fn wait(
@@ -349,6 +409,10 @@ pub trait Queue<A: Api>: Send + Sync {
 /// Serves as a parent for all the encoded command buffers.
 /// Works in bursts of action: one or more command buffers are recorded,
 /// then submitted to a queue, and then it needs to be `reset_all()`.
+///
+/// Encoders are independent of each other, so separate encoders can be
+/// recorded on separate threads, and the resulting command buffers submitted
+/// together from a single thread.
 pub trait CommandEncoder<A: Api>: Send + Sync {
     /// Begin encoding a new command buffer.
     unsafe fn begin_encoding(&mut self, label: Label) -> Result<(), DeviceError>;
@@ -372,8 +436,24 @@ pub trait CommandEncoder<A: Api>: Send + Sync {
 
     // copy operations
 
+    /// Fills a range of a buffer with zeros.
+    ///
+    /// Backends without a native fill command copy from an internal zero buffer.
+    ///
+    /// Valid usage:
+    /// - `buffer` is in `BufferUses::COPY_DST` state
+    /// - `range.start` and `range.end` are multiples of `wgt::COPY_BUFFER_ALIGNMENT`
+    /// - `range` lies within the buffer
+    /// - no pass is active
     unsafe fn clear_buffer(&mut self, buffer: &A::Buffer, range: MemoryRange);
 
+    /// Copy regions between two buffers.
+    ///
+    /// Valid usage:
+    /// - `src` is in `BufferUses::COPY_SRC` state
+    /// - `dst` is in `BufferUses::COPY_DST` state
+    /// - `src` and `dst` are different buffers, since a buffer can't be in both states
+    /// - the regions are within bounds of the respective buffers
     unsafe fn copy_buffer_to_buffer<T>(&mut self, src: &A::Buffer, dst: &A::Buffer, regions: T)
     where
         T: Iterator<Item = BufferCopy>;
@@ -423,6 +503,14 @@ pub trait CommandEncoder<A: Api>: Send + Sync {
         dynamic_offsets: &[wgt::DynamicOffset],
     );
 
+    /// Sets push constant data for the given shader stages.
+    ///
+    /// `offset` is in bytes, while `data` is a slice of 32-bit words.
+    ///
+    /// Valid usage:
+    /// - `offset` is a multiple of `wgt::PUSH_CONSTANT_ALIGNMENT`
+    /// - the byte range starting at `offset` with the size of `data` lies within
+    ///   a push constant range of `layout` that covers exactly `stages`
     unsafe fn set_push_constants(
         &mut self,
         layout: &A::PipelineLayout,
@@ -433,6 +521,11 @@ pub trait CommandEncoder<A: Api>: Send + Sync {
 
     unsafe fn insert_debug_marker(&mut self, label: &str);
     unsafe fn begin_debug_marker(&mut self, group_label: &str);
+    /// Closes the group opened by the last unmatched `begin_debug_marker`.
+    ///
+    /// Valid usage:
+    /// - there is an open debug group in the current pass, or outside of passes
+    ///   if no pass is active
     unsafe fn end_debug_marker(&mut self);
 
     // queries
@@ -464,9 +557,42 @@ pub trait CommandEncoder<A: Api>: Send + Sync {
         format: wgt::IndexFormat,
     );
     unsafe fn set_vertex_buffer<'a>(&mut self, index: u32, binding: BufferBinding<'a, A>);
+    /// Sets the viewport transform.
+    ///
+    /// `rect` is in framebuffer pixels, with the origin at the top-left corner
+    /// and Y pointing down on every backend. `begin_render_pass` resets the
+    /// viewport to cover the whole render pass extent with a `0.0..1.0` depth range.
+    ///
+    /// Valid usage:
+    /// - a render pass is active
+    /// - `rect.w` and `rect.h` are greater than 0
+    /// - `depth_range` is within `0.0..=1.0`
     unsafe fn set_viewport(&mut self, rect: &Rect<f32>, depth_range: Range<f32>);
+    /// Sets the scissor rectangle, in framebuffer pixels with the origin at the top-left corner.
+    ///
+    /// `begin_render_pass` resets it to cover the whole render pass extent.
+    ///
+    /// Valid usage:
+    /// - a render pass is active
+    /// - `rect` is contained in the render pass extent
     unsafe fn set_scissor_rect(&mut self, rect: &Rect<u32>);
+    /// Sets the reference value of the stencil test, for both front and back faces.
+    ///
+    /// The value isn't defined at the start of a render pass,
+    /// and changing the pipeline may reset it on some backends,
+    /// so it needs to be set after `set_render_pipeline` whenever the pipeline
+    /// uses a stencil test.
+    ///
+    /// Valid usage:
+    /// - a render pass is active
     unsafe fn set_stencil_reference(&mut self, value: u32);
+    /// Sets the color used by `BlendFactor::Constant` and `BlendFactor::OneMinusConstant`.
+    ///
+    /// The color stays in effect across draws and pipeline changes until the end
+    /// of the render pass, but isn't defined at its start.
+    ///
+    /// Valid usage:
+    /// - a render pass is active
     unsafe fn set_blend_constants(&mut self, color: &[f32; 4]);
 
     unsafe fn draw(
@@ -496,6 +622,14 @@ pub trait CommandEncoder<A: Api>: Send + Sync {
         offset: wgt::BufferAddress,
         draw_count: u32,
     );
+    /// Draws up to `max_count` times using the arguments tightly packed in `buffer`,
+    /// starting at `offset`. The actual draw count is the `u32` value stored in
+    /// `count_buffer` at `count_offset`, clamped to `max_count`.
+    ///
+    /// Valid usage:
+    /// - `wgt::Features::MULTI_DRAW_INDIRECT_COUNT` is enabled on the device
+    /// - `buffer` and `count_buffer` are in `BufferUses::INDIRECT` state
+    /// - `offset` and `count_offset` are multiples of 4
     unsafe fn draw_indirect_count(
         &mut self,
         buffer: &A::Buffer,
@@ -504,6 +638,7 @@ pub trait CommandEncoder<A: Api>: Send + Sync {
         count_offset: wgt::BufferAddress,
         max_count: u32,
     );
+    /// Indexed version of `draw_indirect_count`, same rules apply.
     unsafe fn draw_indexed_indirect_count(
         &mut self,
         buffer: &A::Buffer,
@@ -521,7 +656,15 @@ pub trait CommandEncoder<A: Api>: Send + Sync {
 
     unsafe fn set_compute_pipeline(&mut self, pipeline: &A::ComputePipeline);
 
+    /// Valid usage:
+    /// - called inside a compute pass, which debug builds assert
     unsafe fn dispatch(&mut self, count: [u32; 3]);
+    /// Dispatches using the `wgt::DispatchIndirectArgs` stored in `buffer` at `offset`.
+    ///
+    /// Valid usage:
+    /// - called inside a compute pass, which debug builds assert
+    /// - `buffer` is in `BufferUses::INDIRECT` state
+    /// - `offset` is a multiple of 4
     unsafe fn dispatch_indirect(&mut self, buffer: &A::Buffer, offset: wgt::BufferAddress);
 }
 
@@ -630,8 +773,13 @@ bitflags!(
 //TODO: it's not intuitive for the backends to consider `LOAD` being optional.
 
 bitflags!(
+    /// Load and store operations of a render pass attachment.
     pub struct AttachmentOps: u8 {
+        /// Load the existing contents at the start of the pass.
+        /// Without it, the attachment is cleared to its `clear_value`.
         const LOAD = 1 << 0;
+        /// Store the results at the end of the pass.
+        /// Without it, the contents are discarded and become undefined.
         const STORE = 1 << 1;
     }
 );
@@ -787,6 +935,9 @@ pub struct AcquiredSurfaceTexture<A: Api> {
     /// The presentation configuration no longer matches
     /// the surface properties exactly, but can still be used to present
     /// to the surface successfully.
+    ///
+    /// The surface should be reconfigured when convenient, for example
+    /// after presenting this texture.
     pub suboptimal: bool,
 }
 
@@ -838,7 +989,9 @@ pub struct TextureViewDescriptor<'a> {
     pub range: wgt::ImageSubresourceRange,
 }
 
-#[derive(Clone, Debug)]
+/// The default matches the WebGPU defaults: clamp-to-edge addressing,
+/// nearest filtering, no LOD clamp and no comparison.
+#[derive(Clone, Debug, Default)]
 pub struct SamplerDescriptor<'a> {
     pub label: Label<'a>,
     pub address_modes: [wgt::AddressMode; 3],
@@ -847,6 +1000,9 @@ pub struct SamplerDescriptor<'a> {
     pub mipmap_filter: wgt::FilterMode,
     pub lod_clamp: Option<Range<f32>>,
     pub compare: Option<wgt::CompareFunction>,
+    /// Valid usage:
+    /// - a power of two no larger than `MAX_ANISOTROPY`
+    /// - only set if the adapter has `DownlevelFlags::ANISOTROPIC_FILTERING`
     pub anisotropy_clamp: Option<NonZeroU8>,
     pub border_color: Option<wgt::SamplerBorderColor>,
 }
@@ -955,7 +1111,13 @@ impl fmt::Debug for NagaShader {
 /// Shader input.
 #[allow(clippy::large_enum_variant)]
 pub enum ShaderInput<'a> {
+    /// A validated naga module, translated by the backend to its native language.
     Naga(NagaShader),
+    /// Raw SPIR-V, passed to the driver as is.
+    ///
+    /// Only the Vulkan backend accepts it, and only with
+    /// `Features::SPIRV_SHADER_PASSTHROUGH` enabled. To use SPIR-V on other
+    /// backends, parse it with naga's SPIR-V front end and pass `Naga` instead.
     SpirV(&'a [u32]),
 }
 
@@ -1056,11 +1218,19 @@ pub struct SurfaceConfiguration {
     /// Number of textures in the swap chain. Must be in
     /// `SurfaceCapabilities::swap_chain_size` range.
     pub swap_chain_size: u32,
-    /// Vertical synchronization mode.
+    /// Vertical synchronization mode. Must be in
+    /// `SurfaceCapabilities::present_modes`.
     pub present_mode: wgt::PresentMode,
     /// Alpha composition mode.
     pub composite_alpha_mode: CompositeAlphaMode,
     /// Format of the surface textures.
+    ///
+    /// The color space follows from the format: `Rgba16Float` requests
+    /// an extended range, linear sRGB swapchain on Metal (EDR), and on
+    /// Vulkan when the surface reports `EXTENDED_SRGB_LINEAR_EXT` for it.
+    /// Everything else, including `Rgba16Float` on the other backends,
+    /// uses non-linear sRGB. There is deliberately no separate color space
+    /// field or capability query.
     pub format: wgt::TextureFormat,
     /// Requested texture extent. Must be in
     /// `SurfaceCapabilities::extents` range.
@@ -1086,6 +1256,9 @@ pub struct BufferBarrier<'a, A: Api> {
 #[derive(Debug, Clone)]
 pub struct TextureBarrier<'a, A: Api> {
     pub texture: &'a A::Texture,
+    /// Valid usage:
+    /// - the mip levels and array layers lie within `texture`
+    /// - the aspect is present in the format of `texture`
     pub range: wgt::ImageSubresourceRange,
     pub usage: Range<TextureUses>,
 }
@@ -1121,6 +1294,19 @@ pub struct TextureCopy {
     pub size: CopyExtent,
 }
 
+/// Region of a copy between a buffer and a texture.
+///
+/// On the buffer side, each row of texel blocks starts `bytes_per_row` bytes
+/// after the previous one, and each image (array layer or depth slice) starts
+/// `rows_per_image` rows after the previous one. Rows are padded up to
+/// `bytes_per_row`, so data read back from a texture has to be un-padded
+/// row by row before it can be treated as tightly packed.
+///
+/// Valid usage:
+/// - `buffer_layout.offset` is a multiple of the format block size
+/// - `buffer_layout.bytes_per_row` covers at least one full row of blocks, and is
+///   a multiple of `Alignments::buffer_copy_pitch` (256 bytes on DX12)
+/// - `size` is a multiple of the format block dimensions
 #[derive(Clone, Debug)]
 pub struct BufferTextureCopy {
     pub buffer_layout: wgt::ImageDataLayout,
@@ -1149,6 +1335,14 @@ impl<A: Api> Clone for Attachment<'_, A> {
 #[derive(Debug)]
 pub struct ColorAttachment<'a, A: Api> {
     pub target: Attachment<'a, A>,
+    /// Single-sampled attachment that the multisampled `target` is resolved
+    /// into at the end of the render pass.
+    ///
+    /// Valid usage:
+    /// - `target` is multisampled, and this attachment is not
+    /// - both attachments have the same format, which supports
+    ///   `TextureFormatCapabilities::MULTISAMPLE_RESOLVE`
+    /// - the attachment is in `TextureUses::COLOR_TARGET` state
     pub resolve_target: Option<Attachment<'a, A>>,
     pub ops: AttachmentOps,
     pub clear_value: wgt::Color,
@@ -1182,6 +1376,12 @@ pub struct RenderPassDescriptor<'a, A: Api> {
     pub color_attachments: &'a [Option<ColorAttachment<'a, A>>],
     pub depth_stencil_attachment: Option<DepthStencilAttachment<'a, A>>,
     pub multiview: Option<NonZeroU32>,
+    /// Query set of `wgt::QueryType::Occlusion` type, used by `begin_query`/`end_query`
+    /// inside of this pass.
+    ///
+    /// Some backends only support binary occlusion, in which case the resolved
+    /// values are non-zero if any samples passed, and zero otherwise.
+    pub occlusion_query_set: Option<&'a A::QuerySet>,
 }
 
 #[derive(Clone, Debug)]
@@ -350,7 +350,9 @@ impl crate::CommandEncoder<super::Api> for super::CommandEncoder {
 
         objc::rc::autoreleasepool(|| {
             let descriptor = mtl::RenderPassDescriptor::new();
-            //TODO: set visibility results buffer
+            if let Some(set) = desc.occlusion_query_set {
+                descriptor.set_visibility_result_buffer(Some(&set.raw_buffer));
+            }
 
             for (i, at) in desc.color_attachments.iter().enumerate() {
                 if let Some(at) = at.as_ref() {
@@ -949,7 +951,11 @@ impl crate::CommandEncoder<super::Api> for super::CommandEncoder {
     }
 
     unsafe fn dispatch(&mut self, count: [u32; 3]) {
-        let encoder = self.state.compute.as_ref().unwrap();
+        let encoder = self
+            .state
+            .compute
+            .as_ref()
+            .expect("dispatch outside of a compute pass");
         let raw_count = mtl::MTLSize {
             width: count[0] as u64,
             height: count[1] as u64,
@@ -959,7 +965,11 @@ impl crate::CommandEncoder<super::Api> for super::CommandEncoder {
     }
 
     unsafe fn dispatch_indirect(&mut self, buffer: &super::Buffer, offset: wgt::BufferAddress) {
-        let encoder = self.state.compute.as_ref().unwrap();
+        let encoder = self
+            .state
+            .compute
+            .as_ref()
+            .expect("dispatch outside of a compute pass");
         encoder.dispatch_thread_groups_indirect(&buffer.raw, offset, self.state.raw_wg_size);
     }
 }
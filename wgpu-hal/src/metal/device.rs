@@ -336,7 +336,19 @@ impl crate::Device<super::Api> for super::Device {
         texture: &super::Texture,
         desc: &crate::TextureViewDescriptor,
     ) -> DeviceResult<super::TextureView> {
-        let raw_format = self.shared.private_caps.map_format(desc.format);
+        let raw_format = match (
+            self.shared.private_caps.map_format(desc.format),
+            desc.range.aspect,
+        ) {
+            // Stencil can only be sampled through a stencil-only view of the combined format.
+            (mtl::MTLPixelFormat::Depth32Float_Stencil8, wgt::TextureAspect::StencilOnly) => {
+                mtl::MTLPixelFormat::X32_Stencil8
+            }
+            (mtl::MTLPixelFormat::Depth24Unorm_Stencil8, wgt::TextureAspect::StencilOnly) => {
+                mtl::MTLPixelFormat::X24_Stencil8
+            }
+            (raw_format, _) => raw_format,
+        };
 
         let raw_type = if texture.raw_type == mtl::MTLTextureType::D2Multisample {
             texture.raw_type
@@ -344,12 +356,21 @@ impl crate::Device<super::Api> for super::Device {
             conv::map_texture_view_dimension(desc.dimension)
         };
 
-        //Note: this doesn't check properly if the mipmap level count or array layer count
-        // is explicitly set to 1.
-        let raw = if raw_format == texture.raw_format
-            && raw_type == texture.raw_type
-            && desc.range == wgt::ImageSubresourceRange::default()
-        {
+        // wgpu-core passes resolved layer counts, so compare the range by what it covers.
+        let covers_texture = desc.range.aspect == wgt::TextureAspect::All
+            && desc.range.base_mip_level == 0
+            && desc
+                .range
+                .mip_level_count
+                .map_or(true, |count| count.get() == texture.mip_levels)
+            && desc.range.base_array_layer == 0
+            && desc
+                .range
+                .array_layer_count
+                .map_or(true, |count| count.get() == texture.array_layers);
+        let aliases_texture =
+            raw_format == texture.raw_format && raw_type == texture.raw_type && covers_texture;
+        let raw = if aliases_texture {
             // Some images are marked as framebuffer-only, and we can't create aliases of them.
             // Also helps working around Metal bugs with aliased array textures.
             texture.raw.to_owned()
@@ -382,10 +403,27 @@ impl crate::Device<super::Api> for super::Device {
         };
 
         let aspects = crate::FormatAspects::from(desc.format);
-        Ok(super::TextureView { raw, aspects })
+        Ok(super::TextureView {
+            raw,
+            aspects,
+            aliases_texture,
+        })
     }
     unsafe fn destroy_texture_view(&self, _view: super::TextureView) {}
 
+    unsafe fn set_buffer_label(&self, buffer: &super::Buffer, label: &str) {
+        buffer.raw.set_label(label);
+    }
+    unsafe fn set_texture_label(&self, texture: &super::Texture, label: &str) {
+        texture.raw.set_label(label);
+    }
+    unsafe fn set_texture_view_label(&self, view: &super::TextureView, label: &str) {
+        // Labeling an alias would rename the parent texture.
+        if !view.aliases_texture {
+            view.raw.set_label(label);
+        }
+    }
+
     unsafe fn create_sampler(
         &self,
         desc: &crate::SamplerDescriptor,
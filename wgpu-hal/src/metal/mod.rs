@@ -115,6 +115,8 @@ impl crate::Instance<Api> for Instance {
                         vendor: 0,
                         device: 0,
                         device_type: shared.private_caps.device_type(),
+                        driver: String::new(),
+                        driver_info: String::new(),
                         backend: wgt::Backend::Metal,
                     },
                     features: shared.private_caps.features(),
@@ -407,6 +409,13 @@ impl Buffer {
     fn as_raw(&self) -> BufferPtr {
         unsafe { NonNull::new_unchecked(self.raw.as_ptr()) }
     }
+
+    /// # Safety
+    ///
+    /// - The buffer must not be released or have its storage modified externally
+    pub unsafe fn raw_handle(&self) -> &mtl::BufferRef {
+        &self.raw
+    }
 }
 
 #[derive(Debug)]
@@ -422,10 +431,21 @@ pub struct Texture {
 unsafe impl Send for Texture {}
 unsafe impl Sync for Texture {}
 
+impl Texture {
+    /// # Safety
+    ///
+    /// - The texture must not be released externally
+    pub unsafe fn raw_handle(&self) -> &mtl::TextureRef {
+        &self.raw
+    }
+}
+
 #[derive(Debug)]
 pub struct TextureView {
     raw: mtl::Texture,
     aspects: crate::FormatAspects,
+    /// The view is the texture itself rather than a separate Metal object.
+    aliases_texture: bool,
 }
 
 unsafe impl Send for TextureView {}
@@ -227,7 +227,7 @@ impl crate::Surface<super::Api> for super::Surface {
     unsafe fn acquire_texture(
         &mut self,
         _timeout_ms: Option<std::time::Duration>, //TODO
-    ) -> Result<Option<crate::AcquiredSurfaceTexture<super::Api>>, crate::SurfaceError> {
+    ) -> Result<crate::AcquiredSurfaceTexture<super::Api>, crate::SurfaceError> {
         let render_layer = self.render_layer.lock();
         let (drawable, texture) = match autoreleasepool(|| {
             render_layer
@@ -235,7 +235,7 @@ impl crate::Surface<super::Api> for super::Surface {
                 .map(|drawable| (drawable.to_owned(), drawable.texture().to_owned()))
         }) {
             Some(pair) => pair,
-            None => return Ok(None),
+            None => return Err(crate::SurfaceError::Timeout),
         };
 
         let suf_texture = super::SurfaceTexture {
@@ -255,10 +255,10 @@ impl crate::Surface<super::Api> for super::Surface {
             present_with_transaction: self.present_with_transaction,
         };
 
-        Ok(Some(crate::AcquiredSurfaceTexture {
+        Ok(crate::AcquiredSurfaceTexture {
             texture: suf_texture,
             suboptimal: false,
-        }))
+        })
     }
 
     unsafe fn discard_texture(&mut self, _texture: super::SurfaceTexture) {}
@@ -110,6 +110,7 @@ impl PhysicalDeviceFeatures {
             // Features is a bitfield so we need to map everything manually
             core: vk::PhysicalDeviceFeatures::builder()
                 .robust_buffer_access(private_caps.robust_buffer_access)
+                .depth_bias_clamp(private_caps.depth_bias_clamp)
                 .independent_blend(downlevel_flags.contains(wgt::DownlevelFlags::INDEPENDENT_BLEND))
                 .sample_rate_shading(
                     downlevel_flags.contains(wgt::DownlevelFlags::MULTISAMPLED_SHADING),
@@ -601,6 +602,8 @@ pub struct PhysicalDeviceCapabilities {
     properties: vk::PhysicalDeviceProperties,
     vulkan_1_2: Option<vk::PhysicalDeviceVulkan12Properties>,
     descriptor_indexing: Option<vk::PhysicalDeviceDescriptorIndexingPropertiesEXT>,
+    /// `VK_KHR_driver_properties`, only queried on pre-1.2 devices.
+    driver: Option<vk::PhysicalDeviceDriverPropertiesKHR>,
     formats: Vec<vk::FormatProperties>,
 }
 
@@ -849,6 +852,12 @@ impl super::InstanceShared {
                 // Get this now to avoid borrowing conflicts later
                 let supports_descriptor_indexing =
                     capabilities.supports_extension(vk::ExtDescriptorIndexingFn::name());
+                // The driver properties are part of the Vk1.2 structure, which must not be
+                // chained together with the extension structure on 1.2+ devices.
+                let api_version =
+                    unsafe { self.raw.get_physical_device_properties(phd) }.api_version;
+                let supports_driver_properties = api_version < vk::API_VERSION_1_2
+                    && capabilities.supports_extension(vk::KhrDriverPropertiesFn::name());
                 // Always add Vk1.2 structure. Will be skipped if unknown.
                 //Note: we can't check if conditional on Vulkan version here, because
                 // we only have the `VkInstance` version but not `VkPhysicalDevice` one.
@@ -868,6 +877,13 @@ impl super::InstanceShared {
                     builder = builder.push_next(next);
                 }
 
+                if supports_driver_properties {
+                    let next = capabilities
+                        .driver
+                        .insert(vk::PhysicalDeviceDriverPropertiesKHR::default());
+                    builder = builder.push_next(next);
+                }
+
                 let mut properites2 = builder.build();
                 unsafe {
                     get_device_properties.get_physical_device_properties2(phd, &mut properites2);
@@ -985,6 +1001,15 @@ impl super::Instance {
 
         let (phd_capabilities, phd_features) = self.shared.inspect(phd);
 
+        let driver_strings = phd_capabilities
+            .driver
+            .map(|driver| (driver.driver_name, driver.driver_info))
+            .or_else(|| {
+                phd_capabilities
+                    .vulkan_1_2
+                    .map(|vk_1_2| (vk_1_2.driver_name, vk_1_2.driver_info))
+            });
+
         let info = wgt::AdapterInfo {
             name: unsafe {
                 CStr::from_ptr(phd_capabilities.properties.device_name.as_ptr())
@@ -1002,6 +1027,16 @@ impl super::Instance {
                 ash::vk::PhysicalDeviceType::CPU => wgt::DeviceType::Cpu,
                 _ => wgt::DeviceType::Other,
             },
+            driver: driver_strings
+                .map(|(name, _)| unsafe {
+                    CStr::from_ptr(name.as_ptr()).to_string_lossy().into_owned()
+                })
+                .unwrap_or_default(),
+            driver_info: driver_strings
+                .map(|(_, info)| unsafe {
+                    CStr::from_ptr(info.as_ptr()).to_string_lossy().into_owned()
+                })
+                .unwrap_or_default(),
             backend: wgt::Backend::Vulkan,
         };
 
@@ -1095,6 +1130,7 @@ impl super::Instance {
                     .image_robustness
                     .map_or(false, |ext| ext.robust_image_access != 0),
             },
+            depth_bias_clamp: phd_features.core.depth_bias_clamp != 0,
         };
         let capabilities = crate::Capabilities {
             limits: phd_capabilities.to_wgpu_limits(&phd_features),
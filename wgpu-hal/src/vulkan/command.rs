@@ -754,6 +754,8 @@ impl crate::CommandEncoder<super::Api> for super::CommandEncoder {
         }
     }
     unsafe fn end_compute_pass(&mut self) {
+        // Outside of compute passes, only graphics work can be recorded.
+        self.bind_point = vk::PipelineBindPoint::GRAPHICS;
         if self.rpass_debug_marker_active {
             self.end_debug_marker();
             self.rpass_debug_marker_active = false
@@ -769,11 +771,21 @@ impl crate::CommandEncoder<super::Api> for super::CommandEncoder {
     }
 
     unsafe fn dispatch(&mut self, count: [u32; 3]) {
+        debug_assert_eq!(
+            self.bind_point,
+            vk::PipelineBindPoint::COMPUTE,
+            "dispatch outside of a compute pass"
+        );
         self.device
             .raw
             .cmd_dispatch(self.active, count[0], count[1], count[2]);
     }
     unsafe fn dispatch_indirect(&mut self, buffer: &super::Buffer, offset: wgt::BufferAddress) {
+        debug_assert_eq!(
+            self.bind_point,
+            vk::PipelineBindPoint::COMPUTE,
+            "dispatch outside of a compute pass"
+        );
         self.device
             .raw
             .cmd_dispatch_indirect(self.active, buffer.raw, offset)
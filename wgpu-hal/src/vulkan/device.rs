@@ -528,7 +528,19 @@ impl super::Device {
             None => vk::SwapchainKHR::null(),
         };
 
-        let color_space = if config.format == wgt::TextureFormat::Rgba16Float {
+        let supports_extended_srgb_linear = config.format == wgt::TextureFormat::Rgba16Float && {
+            profiling::scope!("vkGetPhysicalDeviceSurfaceFormatsKHR");
+            surface
+                .functor
+                .get_physical_device_surface_formats(self.shared.physical_device, surface.raw)
+                .unwrap_or_default()
+                .iter()
+                .any(|sf| {
+                    sf.format == vk::Format::R16G16B16A16_SFLOAT
+                        && sf.color_space == vk::ColorSpaceKHR::EXTENDED_SRGB_LINEAR_EXT
+                })
+        };
+        let color_space = if supports_extended_srgb_linear {
             // Enable wide color gamut mode
             // Vulkan swapchain for Android only supports DISPLAY_P3_NONLINEAR_EXT and EXTENDED_SRGB_LINEAR_EXT
             vk::ColorSpaceKHR::EXTENDED_SRGB_LINEAR_EXT
@@ -847,7 +859,7 @@ impl crate::Device<super::Api> for super::Device {
 
         let mut raw_flags = vk::ImageCreateFlags::empty();
         if desc.dimension == wgt::TextureDimension::D2
-            && desc.size.depth_or_array_layers % 6 == 0
+            && desc.size.depth_or_array_layers >= 6
             && desc.sample_count == 1
             && desc.size.width == desc.size.height
         {
@@ -976,6 +988,19 @@ impl crate::Device<super::Api> for super::Device {
         self.shared.raw.destroy_image_view(view.raw, None);
     }
 
+    unsafe fn set_buffer_label(&self, buffer: &super::Buffer, label: &str) {
+        self.shared
+            .set_object_name(vk::ObjectType::BUFFER, buffer.raw, label);
+    }
+    unsafe fn set_texture_label(&self, texture: &super::Texture, label: &str) {
+        self.shared
+            .set_object_name(vk::ObjectType::IMAGE, texture.raw, label);
+    }
+    unsafe fn set_texture_view_label(&self, view: &super::TextureView, label: &str) {
+        self.shared
+            .set_object_name(vk::ObjectType::IMAGE_VIEW, view.raw, label);
+    }
+
     unsafe fn create_sampler(
         &self,
         desc: &crate::SamplerDescriptor,
@@ -1556,10 +1581,18 @@ impl crate::Device<super::Api> for super::Device {
             }
 
             if ds.bias.is_enabled() {
+                let clamp = if self.shared.private_caps.depth_bias_clamp {
+                    ds.bias.clamp
+                } else {
+                    if ds.bias.clamp != 0.0 {
+                        log::warn!("Depth bias clamp is not supported, ignoring it");
+                    }
+                    0.0
+                };
                 vk_rasterization = vk_rasterization
                     .depth_bias_enable(true)
                     .depth_bias_constant_factor(ds.bias.constant as f32)
-                    .depth_bias_clamp(ds.bias.clamp)
+                    .depth_bias_clamp(clamp)
                     .depth_bias_slope_factor(ds.bias.slope_scale);
             }
         }
@@ -750,7 +750,7 @@ impl crate::Surface<super::Api> for super::Surface {
     unsafe fn acquire_texture(
         &mut self,
         timeout: Option<std::time::Duration>,
-    ) -> Result<Option<crate::AcquiredSurfaceTexture<super::Api>>, crate::SurfaceError> {
+    ) -> Result<crate::AcquiredSurfaceTexture<super::Api>, crate::SurfaceError> {
         let sc = self.swapchain.as_mut().unwrap();
 
         let mut timeout_ns = match timeout {
@@ -780,7 +780,7 @@ impl crate::Surface<super::Api> for super::Surface {
                 Ok(pair) => pair,
                 Err(error) => {
                     return match error {
-                        vk::Result::TIMEOUT => Ok(None),
+                        vk::Result::TIMEOUT => Err(crate::SurfaceError::Timeout),
                         vk::Result::NOT_READY | vk::Result::ERROR_OUT_OF_DATE_KHR => {
                             Err(crate::SurfaceError::Outdated)
                         }
@@ -822,10 +822,10 @@ impl crate::Surface<super::Api> for super::Surface {
                 ),
             },
         };
-        Ok(Some(crate::AcquiredSurfaceTexture {
+        Ok(crate::AcquiredSurfaceTexture {
             texture,
             suboptimal,
-        }))
+        })
     }
 
     unsafe fn discard_texture(&mut self, _texture: super::SurfaceTexture) {}
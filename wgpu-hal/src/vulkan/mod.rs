@@ -167,6 +167,7 @@ struct PrivateCapabilities {
     non_coherent_map_mask: wgt::BufferAddress,
     robust_buffer_access: bool,
     robust_image_access: bool,
+    depth_bias_clamp: bool,
 }
 
 bitflags::bitflags!(
@@ -358,6 +359,15 @@ pub struct Buffer {
     block: Mutex<gpu_alloc::MemoryBlock<vk::DeviceMemory>>,
 }
 
+impl Buffer {
+    /// # Safety
+    ///
+    /// - The buffer handle must not be manually destroyed
+    pub unsafe fn raw_handle(&self) -> vk::Buffer {
+        self.raw
+    }
+}
+
 #[derive(Debug)]
 pub struct Texture {
     raw: vk::Image,
@@ -15,12 +15,14 @@ mod inner {
         let limits = adapter.limits();
     
         println!("Adapter {}:", idx);
-        println!("\tBackend:   {:?}", info.backend);
-        println!("\tName:      {:?}", info.name);
-        println!("\tVendorID:  {:?}", info.vendor);
-        println!("\tDeviceID:  {:?}", info.device);
-        println!("\tType:      {:?}", info.device_type);
-        println!("\tCompliant: {:?}", downlevel.is_webgpu_compliant());
+        println!("\tBackend:    {:?}", info.backend);
+        println!("\tName:       {:?}", info.name);
+        println!("\tVendorID:   {:?}", info.vendor);
+        println!("\tDeviceID:   {:?}", info.device);
+        println!("\tType:       {:?}", info.device_type);
+        println!("\tDriver:     {:?}", info.driver);
+        println!("\tDriverInfo: {:?}", info.driver_info);
+        println!("\tCompliant:  {:?}", downlevel.is_webgpu_compliant());
         println!("\tFeatures:");
         for i in 0..(size_of::<wgpu::Features>() * 8) {
             let bit = wgpu::Features::from_bits(1 << i as u64);
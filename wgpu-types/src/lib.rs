@@ -1075,6 +1075,11 @@ bitflags::bitflags! {
         ///
         /// WebGL doesn't support this.
         const BUFFER_BINDINGS_NOT_16_BYTE_ALIGNED = 1 << 15;
+
+        /// Supports binding stencil-only views of depth-stencil textures as sampled textures.
+        ///
+        /// GLES 3.0 and WebGL2 don't support this.
+        const STENCIL_TEXTURE_VIEWS = 1 << 16;
     }
 }
 
@@ -1136,6 +1141,17 @@ pub struct AdapterInfo {
     pub device: usize,
     /// Type of device
     pub device_type: DeviceType,
+    /// Driver name
+    ///
+    /// Only reported by Vulkan devices that are 1.2+ or support
+    /// `VK_KHR_driver_properties`, empty on other backends.
+    pub driver: String,
+    /// Driver info, such as its version
+    ///
+    /// Reported by Vulkan (under the same conditions as `driver`), GL (the
+    /// `GL_VERSION` string) and DX12 (the user mode driver version). Empty
+    /// on other backends.
+    pub driver_info: String,
     /// Backend used for device
     pub backend: Backend,
 }
@@ -1615,6 +1631,8 @@ pub struct MultisampleState {
     ///
     /// The implicit mask produced for alpha of zero is guaranteed to be zero, and for alpha of one
     /// is guaranteed to be all 1-s.
+    ///
+    /// Requires `count` to be greater than 1.
     pub alpha_to_coverage_enabled: bool,
 }
 
@@ -100,6 +100,7 @@ async fn create_red_image_with_dimensions(
                 },
             })],
             depth_stencil_attachment: None,
+            occlusion_query_set: None,
         });
 
         // Copy the data from the texture to the buffer
@@ -441,6 +441,7 @@ impl framework::Example for Skybox {
                     }),
                     stencil_ops: None,
                 }),
+                occlusion_query_set: None,
             });
 
             rpass.set_bind_group(0, &self.bind_group, &[]);
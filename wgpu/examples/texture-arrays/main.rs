@@ -381,6 +381,7 @@ impl framework::Example for Example {
                 },
             })],
             depth_stencil_attachment: None,
+            occlusion_query_set: None,
         });
 
         rpass.set_pipeline(&self.pipeline);
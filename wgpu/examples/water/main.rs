@@ -753,6 +753,7 @@ impl framework::Example for Example {
                     }),
                     stencil_ops: None,
                 }),
+                occlusion_query_set: None,
             });
 
             rpass.execute_bundles([&self.terrain_bundle]);
@@ -778,6 +779,7 @@ impl framework::Example for Example {
                     }),
                     stencil_ops: None,
                 }),
+                occlusion_query_set: None,
             });
             rpass.set_pipeline(&self.terrain_pipeline);
             rpass.set_bind_group(0, &self.terrain_normal_bind_group, &[]);
@@ -802,6 +804,7 @@ impl framework::Example for Example {
                     depth_ops: None,
                     stencil_ops: None,
                 }),
+                occlusion_query_set: None,
             });
 
             rpass.set_pipeline(&self.water_pipeline);
@@ -582,6 +582,14 @@ mod pass_impl {
             wgpu_render_pass_end_pipeline_statistics_query(self)
         }
 
+        fn begin_occlusion_query(&mut self, query_index: u32) {
+            wgpu_render_pass_begin_occlusion_query(self, query_index)
+        }
+
+        fn end_occlusion_query(&mut self) {
+            wgpu_render_pass_end_occlusion_query(self)
+        }
+
         fn execute_bundles<'a, I: Iterator<Item = &'a wgc::id::RenderBundleId>>(
             &mut self,
             render_bundles: I,
@@ -2070,6 +2078,7 @@ impl crate::Context for Context {
                 label: desc.label.map(Borrowed),
                 color_attachments: Borrowed(&colors),
                 depth_stencil_attachment: depth_stencil.as_ref(),
+                occlusion_query_set: desc.occlusion_query_set.map(|qs| qs.id),
             },
         )
     }
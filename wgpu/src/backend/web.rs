@@ -505,6 +505,16 @@ impl crate::RenderPassInner<Context> for RenderPass {
     fn end_pipeline_statistics_query(&mut self) {
         // Not available in gecko yet
     }
+
+    fn begin_occlusion_query(&mut self, _query_index: u32) {
+        // Not available in gecko yet
+        // self.0.begin_occlusion_query(query_index);
+    }
+
+    fn end_occlusion_query(&mut self) {
+        // Not available in gecko yet
+        // self.0.end_occlusion_query();
+    }
 }
 
 fn map_texture_format(texture_format: wgt::TextureFormat) -> web_sys::GpuTextureFormat {
@@ -1182,6 +1192,8 @@ impl crate::Context for Context {
             vendor: 0,
             device: 0,
             device_type: wgt::DeviceType::Other,
+            driver: String::new(),
+            driver_info: String::new(),
             backend: wgt::Backend::BrowserWebGpu,
         }
     }
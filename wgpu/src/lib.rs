@@ -157,6 +157,8 @@ trait RenderPassInner<Ctx: Context>: RenderInner<Ctx> {
     fn write_timestamp(&mut self, query_set: &Ctx::QuerySetId, query_index: u32);
     fn begin_pipeline_statistics_query(&mut self, query_set: &Ctx::QuerySetId, query_index: u32);
     fn end_pipeline_statistics_query(&mut self);
+    fn begin_occlusion_query(&mut self, query_index: u32);
+    fn end_occlusion_query(&mut self);
     fn execute_bundles<'a, I: Iterator<Item = &'a Ctx::RenderBundleId>>(
         &mut self,
         render_bundles: I,
@@ -1069,6 +1071,7 @@ impl Drop for RenderBundle {
 /// It can be created with [`Device::create_query_set`].
 ///
 /// Corresponds to [WebGPU `GPUQuerySet`](https://gpuweb.github.io/gpuweb/#queryset).
+#[derive(Debug)]
 pub struct QuerySet {
     context: Arc<C>,
     id: <C as Context>::QuerySetId,
@@ -1440,6 +1443,9 @@ pub struct RenderPassDescriptor<'tex, 'desc> {
     pub color_attachments: &'desc [Option<RenderPassColorAttachment<'tex>>],
     /// The depth and stencil attachment of the render pass, if any.
     pub depth_stencil_attachment: Option<RenderPassDepthStencilAttachment<'tex>>,
+    /// The query set that [`RenderPass::begin_occlusion_query`] writes to, if any.
+    /// It must be of type [`QueryType::Occlusion`].
+    pub occlusion_query_set: Option<&'tex QuerySet>,
 }
 
 /// Describes how the vertex buffer is interpreted.
@@ -2912,6 +2918,8 @@ impl<'a> RenderPass<'a> {
     /// The active vertex buffers can be set with [`RenderPass::set_vertex_buffer`].
     ///
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndirect`](crate::util::DrawIndirect).
+    ///
+    /// `indirect_offset` must be a multiple of 4.
     pub fn draw_indirect(&mut self, indirect_buffer: &'a Buffer, indirect_offset: BufferAddress) {
         self.id.draw_indirect(&indirect_buffer.id, indirect_offset);
     }
@@ -2923,6 +2931,8 @@ impl<'a> RenderPass<'a> {
     /// vertex buffers can be set with [`RenderPass::set_vertex_buffer`].
     ///
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndexedIndirect`](crate::util::DrawIndexedIndirect).
+    ///
+    /// `indirect_offset` must be a multiple of 4.
     pub fn draw_indexed_indirect(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -2938,6 +2948,22 @@ impl<'a> RenderPass<'a> {
         self.id
             .execute_bundles(render_bundles.into_iter().map(|rb| &rb.id))
     }
+
+    /// Start an occlusion query on this render pass. It can be ended with
+    /// `end_occlusion_query`. Occlusion queries may not be nested.
+    ///
+    /// The result is written to `query_index` of the pass's
+    /// [`RenderPassDescriptor::occlusion_query_set`]. Some backends only know whether
+    /// any samples passed, in which case the resolved value is only meaningful as zero or non-zero.
+    pub fn begin_occlusion_query(&mut self, query_index: u32) {
+        self.id.begin_occlusion_query(query_index);
+    }
+
+    /// End the occlusion query on this render pass. It can be started with
+    /// `begin_occlusion_query`. Occlusion queries may not be nested.
+    pub fn end_occlusion_query(&mut self) {
+        self.id.end_occlusion_query();
+    }
 }
 
 /// [`Features::MULTI_DRAW_INDIRECT`] must be enabled on the device in order to call these functions.
@@ -2950,6 +2976,8 @@ impl<'a> RenderPass<'a> {
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndirect`](crate::util::DrawIndirect).
     ///
     /// These draw structures are expected to be tightly packed.
+    ///
+    /// `indirect_offset` must be a multiple of 4.
     pub fn multi_draw_indirect(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -2969,6 +2997,8 @@ impl<'a> RenderPass<'a> {
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndexedIndirect`](crate::util::DrawIndexedIndirect).
     ///
     /// These draw structures are expected to be tightly packed.
+    ///
+    /// `indirect_offset` must be a multiple of 4.
     pub fn multi_draw_indexed_indirect(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -3002,6 +3032,8 @@ impl<'a> RenderPass<'a> {
     ///     count: u32, // Number of draw calls to issue.
     /// }
     /// ```
+    ///
+    /// `indirect_offset` and `count_offset` must be multiples of 4.
     pub fn multi_draw_indirect_count(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -3041,6 +3073,8 @@ impl<'a> RenderPass<'a> {
     ///     count: u32, // Number of draw calls to issue.
     /// }
     /// ```
+    ///
+    /// `indirect_offset` and `count_offset` must be multiples of 4.
     pub fn multi_draw_indexed_indirect_count(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -3193,6 +3227,8 @@ impl<'a> ComputePass<'a> {
     /// Dispatches compute work operations, based on the contents of the `indirect_buffer`.
     ///
     /// The structure expected in `indirect_buffer` must conform to [`DispatchIndirect`](crate::util::DispatchIndirect).
+    ///
+    /// `indirect_offset` must be a multiple of 4.
     pub fn dispatch_workgroups_indirect(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -3344,6 +3380,8 @@ impl<'a> RenderBundleEncoder<'a> {
     /// The active vertex buffers can be set with [`RenderBundleEncoder::set_vertex_buffer`].
     ///
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndirect`](crate::util::DrawIndirect).
+    ///
+    /// `indirect_offset` must be a multiple of 4.
     pub fn draw_indirect(&mut self, indirect_buffer: &'a Buffer, indirect_offset: BufferAddress) {
         self.id.draw_indirect(&indirect_buffer.id, indirect_offset);
     }
@@ -3355,6 +3393,8 @@ impl<'a> RenderBundleEncoder<'a> {
     /// vertex buffers can be set with [`RenderBundleEncoder::set_vertex_buffer`].
     ///
     /// The structure expected in `indirect_buffer` must conform to [`DrawIndexedIndirect`](crate::util::DrawIndexedIndirect).
+    ///
+    /// `indirect_offset` must be a multiple of 4.
     pub fn draw_indexed_indirect(
         &mut self,
         indirect_buffer: &'a Buffer,
@@ -0,0 +1,202 @@
+use crate::common::{initialize_test, TestParameters, TestingContext};
+use std::num::NonZeroU32;
+
+// GLES selects the sampled aspect of a depth-stencil texture with a texture
+// parameter, so binding a stencil-only view leaves the texture in stencil mode
+// until a later bind switches it back. This draws a stencil-only view into the
+// left pixel of a 2x1 target, then a depth view of the same texture into the
+// right pixel, and checks that the second draw saw depth again. Views of both
+// aspects can't be put in a bind group, so the depth-only view stands in for
+// them; both have to switch the texture back to depth mode.
+
+const STENCIL_VALUE: u32 = 7;
+const DEPTH_VALUE: f32 = 0.5;
+
+fn create_pipeline(
+    ctx: &TestingContext,
+    shader: &wgpu::ShaderModule,
+    fs_entry_point: &str,
+) -> wgpu::RenderPipeline {
+    ctx.device
+        .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+            label: None,
+            layout: None,
+            vertex: wgpu::VertexState {
+                buffers: &[],
+                entry_point: "vs_main",
+                module: shader,
+            },
+            primitive: wgpu::PrimitiveState::default(),
+            depth_stencil: None,
+            multisample: wgpu::MultisampleState::default(),
+            fragment: Some(wgpu::FragmentState {
+                entry_point: fs_entry_point,
+                module: shader,
+                targets: &[Some(wgpu::ColorTargetState {
+                    format: wgpu::TextureFormat::Rgba8Unorm,
+                    blend: None,
+                    write_mask: wgpu::ColorWrites::ALL,
+                })],
+            }),
+            multiview: None,
+        })
+}
+
+#[test]
+fn depth_after_stencil() {
+    initialize_test(
+        TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::STENCIL_TEXTURE_VIEWS),
+        |ctx| {
+            let shader = ctx
+                .device
+                .create_shader_module(wgpu::include_wgsl!("shader.wgsl"));
+            let stencil_pipeline = create_pipeline(&ctx, &shader, "fs_stencil");
+            let depth_pipeline = create_pipeline(&ctx, &shader, "fs_depth");
+
+            let depth_stencil = ctx.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("depth stencil"),
+                size: wgpu::Extent3d {
+                    width: 1,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Depth24PlusStencil8,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT
+                    | wgpu::TextureUsages::TEXTURE_BINDING,
+            });
+            let attachment_view =
+                depth_stencil.create_view(&wgpu::TextureViewDescriptor::default());
+            let stencil_view = depth_stencil.create_view(&wgpu::TextureViewDescriptor {
+                aspect: wgpu::TextureAspect::StencilOnly,
+                ..Default::default()
+            });
+            let depth_view = depth_stencil.create_view(&wgpu::TextureViewDescriptor {
+                aspect: wgpu::TextureAspect::DepthOnly,
+                ..Default::default()
+            });
+
+            let stencil_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &stencil_pipeline.get_bind_group_layout(0),
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: wgpu::BindingResource::TextureView(&stencil_view),
+                }],
+            });
+            let depth_group = ctx.device.create_bind_group(&wgpu::BindGroupDescriptor {
+                label: None,
+                layout: &depth_pipeline.get_bind_group_layout(0),
+                entries: &[wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: wgpu::BindingResource::TextureView(&depth_view),
+                }],
+            });
+
+            let color_size = wgpu::Extent3d {
+                width: 2,
+                height: 1,
+                depth_or_array_layers: 1,
+            };
+            let color_texture = ctx.device.create_texture(&wgpu::TextureDescriptor {
+                label: Some("color"),
+                size: color_size,
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Rgba8Unorm,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT | wgpu::TextureUsages::COPY_SRC,
+            });
+            let color_view = color_texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+            let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("color readback"),
+                size: wgpu::COPY_BYTES_PER_ROW_ALIGNMENT as u64,
+                usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+                mapped_at_creation: false,
+            });
+
+            let mut encoder = ctx
+                .device
+                .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+
+            encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("clear depth stencil"),
+                color_attachments: &[],
+                depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                    view: &attachment_view,
+                    depth_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(DEPTH_VALUE),
+                        store: true,
+                    }),
+                    stencil_ops: Some(wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(STENCIL_VALUE),
+                        store: true,
+                    }),
+                }),
+                occlusion_query_set: None,
+            });
+
+            let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                label: Some("sample depth stencil"),
+                color_attachments: &[Some(wgpu::RenderPassColorAttachment {
+                    view: &color_view,
+                    resolve_target: None,
+                    ops: wgpu::Operations {
+                        load: wgpu::LoadOp::Clear(wgpu::Color::BLACK),
+                        store: true,
+                    },
+                })],
+                depth_stencil_attachment: None,
+                occlusion_query_set: None,
+            });
+
+            rpass.set_pipeline(&stencil_pipeline);
+            rpass.set_bind_group(0, &stencil_group, &[]);
+            rpass.set_viewport(0.0, 0.0, 1.0, 1.0, 0.0, 1.0);
+            rpass.draw(0..3, 0..1);
+
+            rpass.set_pipeline(&depth_pipeline);
+            rpass.set_bind_group(0, &depth_group, &[]);
+            rpass.set_viewport(1.0, 0.0, 1.0, 1.0, 0.0, 1.0);
+            rpass.draw(0..3, 0..1);
+
+            drop(rpass);
+
+            encoder.copy_texture_to_buffer(
+                wgpu::ImageCopyTexture {
+                    texture: &color_texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d::ZERO,
+                    aspect: wgpu::TextureAspect::All,
+                },
+                wgpu::ImageCopyBuffer {
+                    buffer: &buffer,
+                    layout: wgpu::ImageDataLayout {
+                        offset: 0,
+                        bytes_per_row: NonZeroU32::new(wgpu::COPY_BYTES_PER_ROW_ALIGNMENT),
+                        rows_per_image: None,
+                    },
+                },
+                color_size,
+            );
+
+            ctx.queue.submit(Some(encoder.finish()));
+            let slice = buffer.slice(..);
+            slice.map_async(wgpu::MapMode::Read, |_| ());
+            ctx.device.poll(wgpu::Maintain::Wait);
+            let data = slice.get_mapped_range();
+
+            assert_eq!(data[0] as u32, STENCIL_VALUE, "stencil view sampled depth");
+            let expected_depth = (DEPTH_VALUE * 255.0).round() as i32;
+            assert!(
+                (data[4] as i32 - expected_depth).abs() <= 1,
+                "depth view sampled {} instead of about {}",
+                data[4],
+                expected_depth
+            );
+        },
+    );
+}
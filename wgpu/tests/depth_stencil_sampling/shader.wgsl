@@ -0,0 +1,26 @@
+@vertex
+fn vs_main(@builtin(vertex_index) index: u32) -> @builtin(position) vec4<f32> {
+    // A single triangle covering the whole viewport.
+    let uv = vec2<f32>(f32((index << 1u) & 2u), f32(index & 2u));
+    return vec4<f32>(uv * 2.0 - 1.0, 0.0, 1.0);
+}
+
+@group(0)
+@binding(0)
+var stencil: texture_2d<u32>;
+
+@group(0)
+@binding(1)
+var depth: texture_depth_2d;
+
+@fragment
+fn fs_stencil() -> @location(0) vec4<f32> {
+    let value = textureLoad(stencil, vec2<i32>(0, 0), 0).r;
+    return vec4<f32>(f32(value) / 255.0, 0.0, 0.0, 1.0);
+}
+
+@fragment
+fn fs_depth() -> @location(0) vec4<f32> {
+    let value = textureLoad(depth, vec2<i32>(0, 0), 0);
+    return vec4<f32>(value, 0.0, 0.0, 1.0);
+}
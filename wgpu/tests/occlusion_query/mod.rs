@@ -0,0 +1,163 @@
+use crate::common::{initialize_test, TestParameters};
+
+#[test]
+fn occlusion_query() {
+    initialize_test(TestParameters::default(), |ctx| {
+        let shader = ctx
+            .device
+            .create_shader_module(wgpu::include_wgsl!("shader.wgsl"));
+
+        let pipeline = ctx
+            .device
+            .create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: None,
+                layout: None,
+                vertex: wgpu::VertexState {
+                    buffers: &[],
+                    entry_point: "vs_main",
+                    module: &shader,
+                },
+                primitive: wgpu::PrimitiveState::default(),
+                depth_stencil: Some(wgpu::DepthStencilState {
+                    format: wgpu::TextureFormat::Depth32Float,
+                    depth_write_enabled: true,
+                    depth_compare: wgpu::CompareFunction::Less,
+                    stencil: wgpu::StencilState::default(),
+                    bias: wgpu::DepthBiasState::default(),
+                }),
+                multisample: wgpu::MultisampleState::default(),
+                fragment: None,
+                multiview: None,
+            });
+
+        let depth_view = ctx
+            .device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("depth"),
+                size: wgpu::Extent3d {
+                    width: 64,
+                    height: 64,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Depth32Float,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let query_set = ctx.device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("occlusion"),
+            ty: wgpu::QueryType::Occlusion,
+            count: 2,
+        });
+
+        let buffer = ctx.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("occlusion results"),
+            size: 2 * wgpu::QUERY_SIZE as u64,
+            usage: wgpu::BufferUsages::COPY_DST | wgpu::BufferUsages::MAP_READ,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: true,
+                }),
+                stencil_ops: None,
+            }),
+            occlusion_query_set: Some(&query_set),
+        });
+        rpass.set_pipeline(&pipeline);
+
+        // Fills the depth buffer with 0.5.
+        rpass.draw(0..3, 0..1);
+
+        // Entirely behind the first triangle.
+        rpass.begin_occlusion_query(0);
+        rpass.draw(3..6, 0..1);
+        rpass.end_occlusion_query();
+
+        // Entirely in front of the first triangle.
+        rpass.begin_occlusion_query(1);
+        rpass.draw(6..9, 0..1);
+        rpass.end_occlusion_query();
+
+        drop(rpass);
+
+        encoder.resolve_query_set(&query_set, 0..2, &buffer, 0);
+
+        ctx.queue.submit(Some(encoder.finish()));
+        let slice = buffer.slice(..);
+        slice.map_async(wgpu::MapMode::Read, |_| ());
+        ctx.device.poll(wgpu::Maintain::Wait);
+        let results: Vec<u64> = bytemuck::cast_slice(&*slice.get_mapped_range()).to_vec();
+
+        // Backends with binary occlusion queries only report zero or non-zero.
+        assert_eq!(results[0], 0, "occluded draw passed samples");
+        assert_ne!(results[1], 0, "unoccluded draw passed no samples");
+    })
+}
+
+#[test]
+fn unended_occlusion_query() {
+    initialize_test(TestParameters::default(), |ctx| {
+        let depth_view = ctx
+            .device
+            .create_texture(&wgpu::TextureDescriptor {
+                label: Some("depth"),
+                size: wgpu::Extent3d {
+                    width: 64,
+                    height: 64,
+                    depth_or_array_layers: 1,
+                },
+                mip_level_count: 1,
+                sample_count: 1,
+                dimension: wgpu::TextureDimension::D2,
+                format: wgpu::TextureFormat::Depth32Float,
+                usage: wgpu::TextureUsages::RENDER_ATTACHMENT,
+            })
+            .create_view(&wgpu::TextureViewDescriptor::default());
+
+        let query_set = ctx.device.create_query_set(&wgpu::QuerySetDescriptor {
+            label: Some("occlusion"),
+            ty: wgpu::QueryType::Occlusion,
+            count: 1,
+        });
+
+        let mut encoder = ctx
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+
+        ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let mut rpass = encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+            label: None,
+            color_attachments: &[],
+            depth_stencil_attachment: Some(wgpu::RenderPassDepthStencilAttachment {
+                view: &depth_view,
+                depth_ops: Some(wgpu::Operations {
+                    load: wgpu::LoadOp::Clear(1.0),
+                    store: true,
+                }),
+                stencil_ops: None,
+            }),
+            occlusion_query_set: Some(&query_set),
+        });
+        rpass.begin_occlusion_query(0);
+        drop(rpass);
+
+        assert!(
+            pollster::block_on(ctx.device.pop_error_scope()).is_some(),
+            "render pass ended with an active occlusion query"
+        );
+    })
+}
@@ -0,0 +1,9 @@
+// Every three vertices form a triangle covering the whole target, at a depth picked by the triangle's index.
+@vertex
+fn vs_main(@builtin(vertex_index) vertex_index: u32) -> @builtin(position) vec4<f32> {
+    var depths = array<f32, 3>(0.5, 0.8, 0.2);
+    let corner = vertex_index % 3u;
+    let x = f32(corner & 1u) * 4.0 - 1.0;
+    let y = f32(corner >> 1u) * 4.0 - 1.0;
+    return vec4<f32>(x, y, depths[vertex_index / 3u], 1.0);
+}
@@ -2,10 +2,13 @@
 mod common;
 
 mod clear_texture;
+mod depth_stencil_sampling;
 mod device;
 mod example_wgsl;
 mod instance;
+mod occlusion_query;
 mod poll;
 mod shader_primitive_index;
+mod texture_view_creation;
 mod vertex_indices;
 mod zero_init_texture_after_discard;
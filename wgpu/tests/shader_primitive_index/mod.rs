@@ -194,6 +194,7 @@ fn pulling_common(
         })],
         depth_stencil_attachment: None,
         label: None,
+        occlusion_query_set: None,
     });
 
     rpass.set_pipeline(&pipeline);
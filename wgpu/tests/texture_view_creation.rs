@@ -0,0 +1,87 @@
+use std::num::NonZeroU32;
+
+use crate::common::{initialize_test, TestParameters, TestingContext};
+
+fn create_texture(ctx: &TestingContext, width: u32, height: u32, layers: u32) -> wgpu::Texture {
+    ctx.device.create_texture(&wgpu::TextureDescriptor {
+        label: None,
+        size: wgpu::Extent3d {
+            width,
+            height,
+            depth_or_array_layers: layers,
+        },
+        mip_level_count: 1,
+        sample_count: 1,
+        dimension: wgpu::TextureDimension::D2,
+        format: wgpu::TextureFormat::Rgba8Unorm,
+        usage: wgpu::TextureUsages::TEXTURE_BINDING,
+    })
+}
+
+fn view_is_valid(
+    ctx: &TestingContext,
+    texture: &wgpu::Texture,
+    dimension: wgpu::TextureViewDimension,
+    base_array_layer: u32,
+    array_layer_count: Option<u32>,
+) -> bool {
+    ctx.device.push_error_scope(wgpu::ErrorFilter::Validation);
+    let _view = texture.create_view(&wgpu::TextureViewDescriptor {
+        dimension: Some(dimension),
+        base_array_layer,
+        array_layer_count: array_layer_count.and_then(NonZeroU32::new),
+        ..Default::default()
+    });
+    pollster::block_on(ctx.device.pop_error_scope()).is_none()
+}
+
+#[test]
+fn cube_view_layers() {
+    initialize_test(TestParameters::default(), |ctx| {
+        use wgpu::TextureViewDimension::Cube;
+
+        let texture = create_texture(&ctx, 16, 16, 12);
+        assert!(view_is_valid(&ctx, &texture, Cube, 0, None));
+        assert!(view_is_valid(&ctx, &texture, Cube, 6, None));
+        assert!(view_is_valid(&ctx, &texture, Cube, 3, Some(6)));
+        assert!(!view_is_valid(&ctx, &texture, Cube, 0, Some(5)));
+        assert!(!view_is_valid(&ctx, &texture, Cube, 7, None));
+
+        let non_square = create_texture(&ctx, 16, 8, 6);
+        assert!(!view_is_valid(&ctx, &non_square, Cube, 0, None));
+    })
+}
+
+#[test]
+fn cube_array_view_layers() {
+    initialize_test(
+        TestParameters::default().downlevel_flags(wgpu::DownlevelFlags::CUBE_ARRAY_TEXTURES),
+        |ctx| {
+            use wgpu::TextureViewDimension::CubeArray;
+
+            let texture = create_texture(&ctx, 16, 16, 12);
+            assert!(view_is_valid(&ctx, &texture, CubeArray, 0, None));
+            assert!(view_is_valid(&ctx, &texture, CubeArray, 0, Some(12)));
+            assert!(view_is_valid(&ctx, &texture, CubeArray, 6, None));
+            assert!(!view_is_valid(&ctx, &texture, CubeArray, 0, Some(8)));
+            assert!(!view_is_valid(&ctx, &texture, CubeArray, 3, None));
+            // Starting at the last layer leaves no layers for the view.
+            assert!(!view_is_valid(&ctx, &texture, CubeArray, 12, None));
+        },
+    )
+}
+
+#[test]
+fn array_view_layers() {
+    initialize_test(TestParameters::default(), |ctx| {
+        use wgpu::TextureViewDimension::D2Array;
+
+        let texture = create_texture(&ctx, 16, 16, 4);
+        assert!(view_is_valid(&ctx, &texture, D2Array, 0, None));
+        assert!(view_is_valid(&ctx, &texture, D2Array, 3, None));
+        assert!(view_is_valid(&ctx, &texture, D2Array, 1, Some(2)));
+        assert!(!view_is_valid(&ctx, &texture, D2Array, 3, Some(2)));
+        // Starting at the last layer leaves no layers for the view.
+        assert!(!view_is_valid(&ctx, &texture, D2Array, 4, None));
+    })
+}